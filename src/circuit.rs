@@ -1,4 +1,5 @@
-use crate::rns::{decompose_fe as decompose, fe_to_big, Common, Integer, Limb};
+use crate::rns::{compose, decompose_fe as decompose, fe_to_big, Common, Integer, Limb};
+use crate::NUMBER_OF_LIMBS;
 use halo2::plonk::Error;
 use halo2::{
     arithmetic::FieldExt,
@@ -114,17 +115,18 @@ impl<F: FieldExt> UnassignedInteger<F> {
 pub struct AssignedInteger<F: FieldExt> {
     limbs: Vec<AssignedLimb<F>>,
     native_value: AssignedValue<F>,
+    bit_len_limb: usize,
 }
 
 impl<F: FieldExt> AssignedInteger<F> {
-    pub fn new(limbs: Vec<AssignedLimb<F>>, native_value: AssignedValue<F>) -> Self {
-        AssignedInteger { limbs, native_value }
+    pub fn new(limbs: Vec<AssignedLimb<F>>, native_value: AssignedValue<F>, bit_len_limb: usize) -> Self {
+        AssignedInteger { limbs, native_value, bit_len_limb }
     }
 
     pub fn integer(&self) -> Option<Integer<F>> {
         self.limbs[0].value.as_ref().map(|_| {
-            let limbs = self.limbs.iter().map(|limb| limb.value.clone().unwrap()).collect();
-            Integer::new(limbs)
+            let limbs = self.limbs.iter().map(|limb| limb.value.unwrap()).collect();
+            Integer::new_with_bit_len_limb(limbs, self.bit_len_limb)
         })
     }
 
@@ -139,6 +141,40 @@ impl<F: FieldExt> AssignedInteger<F> {
     pub fn native(&self) -> AssignedValue<F> {
         self.native_value.clone()
     }
+
+    /// Upper bounds of limbs tracked through operations since the last range check
+    pub fn max_vals(&self) -> [big_uint; NUMBER_OF_LIMBS] {
+        [
+            self.limbs[0].max_val.clone(),
+            self.limbs[1].max_val.clone(),
+            self.limbs[2].max_val.clone(),
+            self.limbs[3].max_val.clone(),
+        ]
+    }
+
+    /// Upper bound of the composed value
+    pub fn max_value(&self) -> big_uint {
+        compose(self.max_vals().to_vec(), self.bit_len_limb)
+    }
+
+    /// Returns a copy with tightened limb bounds. No constraints are added so the caller is
+    /// responsible for having enforced the new bounds, otherwise later gates may overflow silently.
+    pub fn narrow_max(&self, new_max_vals: [big_uint; NUMBER_OF_LIMBS]) -> Self {
+        let mut narrowed = self.clone();
+        for (limb, max_val) in narrowed.limbs.iter_mut().zip(new_max_vals.iter()) {
+            assert!(*max_val <= limb.max_val, "bound of a limb can only be narrowed");
+            limb.max_val = max_val.clone();
+        }
+        narrowed
+    }
+
+    /// Composed witness value, `None` if any limb is not witnessed
+    pub fn value(&self) -> Option<big_uint> {
+        if self.limbs.iter().any(|limb| limb.value.is_none()) {
+            return None;
+        }
+        self.integer().map(|integer| integer.value())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -205,3 +241,34 @@ impl<F: FieldExt> UnassignedValue<F> {
         AssignedValue::new(cell, self.value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use halo2::arithmetic::FieldExt;
+    use halo2::circuit::layouter::{RegionLayouter, RegionShape};
+    use halo2::circuit::Region;
+    use halo2::plonk::Error;
+
+    // Smallest circuit size that fits the rows of `layout` and the lookup table of `bit_len_lookup` bit values.
+    // Without lookup tables every ranged bit takes a row so a fixed size can't fit all tests
+    pub(crate) fn test_k<F: FieldExt, Chip>(
+        chip: &Chip,
+        bit_len_lookup: usize,
+        layout: impl FnOnce(&Chip, &mut Region<'_, F>, &mut usize) -> Result<(), Error>,
+    ) -> u32 {
+        let mut shape = RegionShape::new(0.into());
+        let mut region = Region::from(&mut shape as &mut dyn RegionLayouter<F>);
+        let offset = &mut 0;
+        if let Err(e) = layout(chip, &mut region, offset) {
+            panic!("{:#?}", e);
+        }
+
+        // leave room for the rows reserved for blinding
+        let k = (*offset + 16).next_power_of_two().trailing_zeros();
+        if cfg!(feature = "no_lookup") {
+            k
+        } else {
+            std::cmp::max(k, (bit_len_lookup + 1) as u32)
+        }
+    }
+}
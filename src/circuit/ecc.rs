@@ -1,14 +1,25 @@
-use crate::rns::{Common, Integer, Rns};
+use crate::rns::{Integer, Rns};
 
-use super::{integer::IntegerConfig, AssignedInteger};
+use super::{integer::IntegerConfig, AssignedCondition, AssignedInteger};
 use crate::circuit::integer::{IntegerChip, IntegerInstructions};
+use crate::circuit::main_gate::{MainGate, MainGateInstructions};
 use crate::circuit::UnassignedInteger;
 use crate::NUMBER_OF_LIMBS;
-use halo2::arithmetic::{CurveAffine, FieldExt};
+use halo2::arithmetic::CurveAffine;
+use halo2::circuit::layouter::{RegionLayouter, RegionShape};
 use halo2::circuit::Region;
-use halo2::pasta::group::Curve;
 use halo2::plonk::Error;
 use num_bigint::BigUint as big_uint;
+use num_traits::Zero;
+
+mod add;
+mod double;
+mod mul;
+mod on_curve;
+mod sub;
+
+/// Number of signed digits consumed at each addition of `mul_naf`
+const NAF_WINDOW_SIZE: usize = 4;
 
 // TODO: are these traits all available?
 #[derive(Default, Clone, Debug)]
@@ -45,6 +56,8 @@ impl<C: CurveAffine> Point<C> {
 pub struct AssignedPoint<C: CurveAffine> {
     pub x: AssignedInteger<C::ScalarExt>,
     pub y: AssignedInteger<C::ScalarExt>,
+    // set if the point is the point at infinity, coordinates are meaningless then
+    pub z: AssignedCondition<C::ScalarExt>,
 }
 
 /// Linear combination term
@@ -60,26 +73,45 @@ pub struct EccConfig {
 }
 
 /// E is the emulated curve, C is the native curve
+/// Coordinates are wrong field integers so base field of E may differ from the native field
 pub struct EccChip<E: CurveAffine, C: CurveAffine> {
-    // config: EccConfig,
-    // e_scalar_field: IntegerChip<E::ScalarExt, C::ScalarExt>
     // TODO: is `pub` necessary?
     pub config: EccConfig,
     pub e_base_field: IntegerChip<E::Base, C::ScalarExt>,
+    pub e_scalar_field: IntegerChip<E::ScalarExt, C::ScalarExt>,
 }
 
 pub trait EccInstruction<E: CurveAffine, C: CurveAffine> {
     fn assign_point(&self, region: &mut Region<'_, C::ScalarExt>, point: Option<Point<C>>, offset: &mut usize) -> Result<AssignedPoint<C>, Error>;
     fn assert_is_on_curve(&self, region: &mut Region<'_, C::ScalarExt>, point: AssignedPoint<C>, offset: &mut usize) -> Result<(), Error>;
-    fn assert_equal(
+    /// Each point gets its own on curve check and only the curve constants are shared. Terms are
+    /// not folded into a random linear combination as there is no verifier challenge to draw from
+    fn assert_each_on_curve(&self, region: &mut Region<'_, C::ScalarExt>, points: &[AssignedPoint<C>], offset: &mut usize) -> Result<(), Error>;
+    fn assert_equal(&self, region: &mut Region<'_, C::ScalarExt>, p0: &AssignedPoint<C>, p1: &AssignedPoint<C>, offset: &mut usize) -> Result<(), Error>;
+    fn assert_equal_constant(&self, region: &mut Region<'_, C::ScalarExt>, p: &AssignedPoint<C>, c: Point<C>, offset: &mut usize) -> Result<(), Error>;
+    /// Returns `p0` if `cond` is set and `p1` otherwise, identity flag is selected along with coordinates
+    fn select(
         &self,
         region: &mut Region<'_, C::ScalarExt>,
-        p0: AssignedPoint<C>,
-        p1: AssignedPoint<C>,
+        cond: &AssignedCondition<C::ScalarExt>,
+        p0: &AssignedPoint<C>,
+        p1: &AssignedPoint<C>,
         offset: &mut usize,
     ) -> Result<AssignedPoint<C>, Error>;
     fn add(&self, region: &mut Region<'_, C::ScalarExt>, p0: AssignedPoint<C>, p1: AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error>;
+    fn sub(&self, region: &mut Region<'_, C::ScalarExt>, p0: AssignedPoint<C>, p1: AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error>;
+    fn neg(&self, region: &mut Region<'_, C::ScalarExt>, p: &AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error>;
     fn double(&self, region: &mut Region<'_, C::ScalarExt>, p: AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error>;
+    /// Montgomery ladder step, `(2 * r_0, r_0 + r_1)` if `bit` is not set and `(r_0 + r_1, 2 * r_1)` otherwise.
+    /// Registers must not be the point at infinity and `r_0 + r_1` must not be the point at infinity
+    fn ladder_step(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        r0: &AssignedPoint<C>,
+        r1: &AssignedPoint<C>,
+        bit: &AssignedCondition<C::ScalarExt>,
+        offset: &mut usize,
+    ) -> Result<(AssignedPoint<C>, AssignedPoint<C>), Error>;
     fn mul_var(
         &self,
         region: &mut Region<'_, C::ScalarExt>,
@@ -87,6 +119,13 @@ pub trait EccInstruction<E: CurveAffine, C: CurveAffine> {
         e: AssignedInteger<C::ScalarExt>,
         offset: &mut usize,
     ) -> Result<AssignedPoint<C>, Error>;
+    fn mul_naf(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        p: AssignedPoint<C>,
+        e: AssignedInteger<C::ScalarExt>,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error>;
     fn mul_fix(&self, region: &mut Region<'_, C::ScalarExt>, p: E, e: AssignedInteger<C::ScalarExt>, offset: &mut usize) -> Result<AssignedPoint<C>, Error>;
     fn multi_exp(&self, region: &mut Region<'_, C::ScalarExt>, terms: Vec<Term<C>>, offset: &mut usize) -> Result<AssignedPoint<C>, Error>;
     fn combine(&self, region: &mut Region<'_, C::ScalarExt>, terms: Vec<Term<C>>, u: C::ScalarExt, offset: &mut usize) -> Result<AssignedPoint<C>, Error>;
@@ -106,52 +145,75 @@ impl<E: CurveAffine, C: CurveAffine> EccInstruction<E, C> for EccChip<E, C> {
             self.e_base_field.rns.bit_len_limb,
             offset,
         )?;
-        Ok(AssignedPoint { x, y })
+        let z = self.main_gate().assign_bit_constant(region, false, offset)?;
+        Ok(AssignedPoint { x, y, z })
     }
 
     fn assert_is_on_curve(&self, region: &mut Region<'_, C::ScalarExt>, point: AssignedPoint<C>, offset: &mut usize) -> Result<(), Error> {
-        // TODO
+        self._assert_each_on_curve(region, &[point], offset)
+    }
+
+    fn assert_each_on_curve(&self, region: &mut Region<'_, C::ScalarExt>, points: &[AssignedPoint<C>], offset: &mut usize) -> Result<(), Error> {
+        self._assert_each_on_curve(region, points, offset)
+    }
+
+    fn assert_equal(&self, region: &mut Region<'_, C::ScalarExt>, p0: &AssignedPoint<C>, p1: &AssignedPoint<C>, offset: &mut usize) -> Result<(), Error> {
+        // both points are either the point at infinity or not
+        self.main_gate().assert_equal(region, p0.z.clone(), p1.z.clone(), offset)?;
+        // coordinates of the point at infinity are meaningless so they are compared to themselves
+        let x = &self.e_base_field.cond_select(region, &p0.x, &p1.x, &p0.z, offset)?;
+        let y = &self.e_base_field.cond_select(region, &p0.y, &p1.y, &p0.z, offset)?;
+        self.e_base_field.assert_equal(region, &p0.x, x, offset)?;
+        self.e_base_field.assert_equal(region, &p0.y, y, offset)?;
         Ok(())
     }
 
-    fn assert_equal(
+    fn select(
         &self,
         region: &mut Region<'_, C::ScalarExt>,
-        p0: AssignedPoint<C>,
-        p1: AssignedPoint<C>,
+        cond: &AssignedCondition<C::ScalarExt>,
+        p0: &AssignedPoint<C>,
+        p1: &AssignedPoint<C>,
         offset: &mut usize,
     ) -> Result<AssignedPoint<C>, Error> {
-        Ok(p0)
+        self._select(region, cond, p0, p1, offset)
+    }
+
+    fn assert_equal_constant(&self, region: &mut Region<'_, C::ScalarExt>, p: &AssignedPoint<C>, c: Point<C>, offset: &mut usize) -> Result<(), Error> {
+        // constant coordinates are fixed by selectors so the check is bound to the verifying key
+        let x = &self.e_base_field.assign_constant(region, c.x, offset)?;
+        let y = &self.e_base_field.assign_constant(region, c.y, offset)?;
+        self.e_base_field.assert_strict_equal(region, &p.x, x, offset)?;
+        self.e_base_field.assert_strict_equal(region, &p.y, y, offset)?;
+        // constant point is never the point at infinity
+        self.main_gate().assert_zero(region, p.z.clone(), offset)
     }
 
     fn add(&self, region: &mut Region<'_, C::ScalarExt>, p0: AssignedPoint<C>, p1: AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
-        let to_base = |x: Integer<C::ScalarExt>| -> E::Base {
-            let bytes_le = x.value().to_bytes_le();
-            let mut u256 = [0u8; 32];
-            u256[..bytes_le.len()].copy_from_slice(&bytes_le);
-            E::Base::from_bytes(&u256).unwrap()
-        };
-        let to_scalar = |x: Integer<C::ScalarExt>| -> E::Scalar {
-            let bytes_le = x.value().to_bytes_le();
-            let mut u256 = [0u8; 32];
-            u256[..bytes_le.len()].copy_from_slice(&bytes_le);
-            E::Scalar::from_bytes(&u256).unwrap()
-        };
-        let p0_x = p0.x.integer().map(to_base);
-        let p0_y = p0.y.integer().map(to_base);
-        let p1_x = p1.x.integer().map(to_base);
-        let p1_y = p1.y.integer().map(to_base);
-        let sum = p0_x.map(|p0_x| {
-            let p0 = E::from_xy(p0_x, p0_y.unwrap()).unwrap();
-            let p1 = E::from_xy(p1_x.unwrap(), p1_y.unwrap()).unwrap();
-            let sum = p0.add(p1).to_affine();
-            Point::new_from_point(sum, NUMBER_OF_LIMBS, self.e_base_field.rns.bit_len_limb)
-        });
-        self.assign_point(region, sum, offset)
+        self._add(region, &p0, &p1, offset)
+    }
+
+    fn sub(&self, region: &mut Region<'_, C::ScalarExt>, p0: AssignedPoint<C>, p1: AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
+        self._sub(region, &p0, &p1, offset)
+    }
+
+    fn neg(&self, region: &mut Region<'_, C::ScalarExt>, p: &AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
+        self._neg(region, p, offset)
     }
 
     fn double(&self, region: &mut Region<'_, C::ScalarExt>, p: AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
-        Ok(p)
+        self._double(region, &p, offset)
+    }
+
+    fn ladder_step(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        r0: &AssignedPoint<C>,
+        r1: &AssignedPoint<C>,
+        bit: &AssignedCondition<C::ScalarExt>,
+        offset: &mut usize,
+    ) -> Result<(AssignedPoint<C>, AssignedPoint<C>), Error> {
+        self._ladder_step(region, r0, r1, bit, offset)
     }
 
     fn mul_var(
@@ -161,31 +223,21 @@ impl<E: CurveAffine, C: CurveAffine> EccInstruction<E, C> for EccChip<E, C> {
         e: AssignedInteger<C::ScalarExt>,
         offset: &mut usize,
     ) -> Result<AssignedPoint<C>, Error> {
-        let to_base = |x: Integer<C::ScalarExt>| -> E::Base {
-            let bytes_le = x.value().to_bytes_le();
-            let mut u256 = [0u8; 32];
-            u256[..bytes_le.len()].copy_from_slice(&bytes_le);
-            E::Base::from_bytes(&u256).unwrap()
-        };
-        let to_scalar = |x: Integer<C::ScalarExt>| -> E::Scalar {
-            let bytes_le = x.value().to_bytes_le();
-            let mut u256 = [0u8; 32];
-            u256[..bytes_le.len()].copy_from_slice(&bytes_le);
-            E::Scalar::from_bytes(&u256).unwrap()
-        };
-        let x = p.x.integer().map(to_base);
-        let y = p.y.integer().map(to_base);
-        let point = x.map(|x| {
-            let p = E::from_xy(x, y.unwrap()).unwrap();
-            let out = p.mul(e.integer().map(to_scalar).unwrap()).to_affine();
-            Point::new_from_point(out, NUMBER_OF_LIMBS, self.e_base_field.rns.bit_len_limb)
-        });
-        self.assign_point(region, point, offset)
+        self._mul_var(region, &p, &e, offset)
+    }
+
+    fn mul_naf(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        p: AssignedPoint<C>,
+        e: AssignedInteger<C::ScalarExt>,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error> {
+        self._mul_naf(region, &p, &e, NAF_WINDOW_SIZE, offset)
     }
 
     fn mul_fix(&self, region: &mut Region<'_, C::ScalarExt>, p: E, e: AssignedInteger<C::ScalarExt>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
-        let point = Point::new_from_point(p, NUMBER_OF_LIMBS, self.e_base_field.rns.bit_len_limb);
-        let assigned_point = self.assign_point(region, Some(point), offset)?;
+        let assigned_point = self.assign_constant_point(region, p, offset)?;
         self.mul_var(region, assigned_point, e, offset)
     }
 
@@ -197,3 +249,1689 @@ impl<E: CurveAffine, C: CurveAffine> EccInstruction<E, C> for EccChip<E, C> {
         unimplemented!();
     }
 }
+
+impl<E: CurveAffine, C: CurveAffine> EccChip<E, C> {
+    pub fn new(config: EccConfig, rns_base: Rns<E::Base, C::ScalarExt>, rns_scalar: Rns<E::ScalarExt, C::ScalarExt>) -> Self {
+        let e_base_field = IntegerChip::<E::Base, C::ScalarExt>::new(config.integer_chip_config.clone(), rns_base);
+        let e_scalar_field = IntegerChip::<E::ScalarExt, C::ScalarExt>::new(config.integer_chip_config.clone(), rns_scalar);
+        EccChip {
+            config,
+            e_base_field,
+            e_scalar_field,
+        }
+    }
+
+    fn main_gate(&self) -> MainGate<C::ScalarExt> {
+        self.e_base_field.main_gate()
+    }
+
+    pub(crate) fn assign_identity(&self, region: &mut Region<'_, C::ScalarExt>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
+        // coordinates of the point at infinity are not used so (0, 0) is witnessed
+        let zero = self.e_base_field.rns.new_from_big(big_uint::zero());
+        let x = self.e_base_field.assign_integer(region, Some(zero.clone()), offset)?;
+        let y = self.e_base_field.assign_integer(region, Some(zero), offset)?;
+        let z = self.main_gate().assign_bit_constant(region, true, offset)?;
+        Ok(AssignedPoint { x, y, z })
+    }
+
+    /// Coordinates are fixed by selectors so the point is bound to the verifying key
+    fn assign_constant_point(&self, region: &mut Region<'_, C::ScalarExt>, p: E, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
+        let point: Point<C> = Point::new_from_point(p, NUMBER_OF_LIMBS, self.e_base_field.rns.bit_len_limb);
+        let x = self.e_base_field.assign_constant(region, point.x, offset)?;
+        let y = self.e_base_field.assign_constant(region, point.y, offset)?;
+        let z = self.main_gate().assign_bit_constant(region, false, offset)?;
+        Ok(AssignedPoint { x, y, z })
+    }
+
+    /// Generator of the emulated curve, assign it once and reuse it within a region
+    pub fn assign_generator(&self, region: &mut Region<'_, C::ScalarExt>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
+        let generator = self.assign_constant_point(region, E::generator(), offset)?;
+        self.assert_is_on_curve(region, generator.clone(), offset)?;
+        Ok(generator)
+    }
+
+    /// Multiplies `p` by the order of the scalar field and expects the point at infinity,
+    /// which holds only if `p` is in the prime order subgroup. It costs a full double and add
+    /// over the bits of the order, so with a 256 bit order tests run it with k = 19
+    pub fn assert_in_subgroup(&self, region: &mut Region<'_, C::ScalarExt>, p: &AssignedPoint<C>, offset: &mut usize) -> Result<(), Error> {
+        let order = self.e_scalar_field.rns.wrong_modulus_integer();
+        let order = &self.e_scalar_field.assign_constant(region, order, offset)?;
+        let result = self._mul_var(region, p, order, offset)?;
+        let one = self.main_gate().assign_bit_constant(region, true, offset)?;
+        self.main_gate().assert_equal(region, result.z, one, offset)
+    }
+
+    /// Replaces coordinates of the point at infinity with `(0, 0)` so that identities from different paths are strictly equal
+    pub fn canonicalize_identity(&self, region: &mut Region<'_, C::ScalarExt>, p: &AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
+        let zero = self.e_base_field.rns.new_from_big(big_uint::zero());
+        let zero = &self.e_base_field.assign_constant(region, zero, offset)?;
+        let x = self.e_base_field.cond_select(region, zero, &p.x, &p.z, offset)?;
+        let y = self.e_base_field.cond_select(region, zero, &p.y, &p.z, offset)?;
+        Ok(AssignedPoint { x, y, z: p.z.clone() })
+    }
+
+    /// Rows that `op` advances the offset by, assignments go to a region that only records its shape
+    pub fn count_rows<R>(&self, op: impl FnOnce(&Self, &mut Region<'_, C::ScalarExt>, &mut usize) -> Result<R, Error>) -> Result<usize, Error> {
+        let mut shape = RegionShape::new(0.into());
+        let mut region = Region::from(&mut shape as &mut dyn RegionLayouter<C::ScalarExt>);
+        let offset = &mut 0;
+        op(self, &mut region, offset)?;
+        Ok(*offset)
+    }
+
+    pub(crate) fn _select(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        cond: &AssignedCondition<C::ScalarExt>,
+        p0: &AssignedPoint<C>,
+        p1: &AssignedPoint<C>,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error> {
+        let x = self.e_base_field.cond_select(region, &p0.x, &p1.x, cond, offset)?;
+        let y = self.e_base_field.cond_select(region, &p0.y, &p1.y, cond, offset)?;
+        let z = self.main_gate().cond_select(region, p0.z.clone(), p1.z.clone(), cond, offset)?;
+        let z = AssignedCondition::new(z.cell, z.value);
+        Ok(AssignedPoint { x, y, z })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssignedPoint, EccChip, EccConfig, EccInstruction, Point};
+    use crate::circuit::integer::{IntegerChip, IntegerInstructions};
+    use crate::circuit::main_gate::{MainGate, MainGateConfig, MainGateInstructions};
+    use crate::circuit::range::{RangeChip, RangeInstructions};
+    use crate::rns::{fe_to_big, field_modulus, Integer, Rns};
+    use crate::{BIT_LEN_LIMB, NUMBER_OF_LIMBS, NUMBER_OF_LOOKUP_LIMBS};
+    use group::{prime::PrimeCurveAffine, Curve};
+    use halo2::arithmetic::{CurveAffine, Field, FieldExt};
+    use halo2::circuit::{Layouter, SimpleFloorPlanner};
+    use halo2::dev::MockProver;
+    use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use proptest::prelude::*;
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitConfig {
+        main_gate_config: MainGateConfig,
+        ecc_config: EccConfig,
+    }
+
+    impl TestCircuitConfig {
+        fn overflow_bit_lengths() -> Vec<usize> {
+            vec![2, 3]
+        }
+
+        fn bit_len_lookup() -> usize {
+            BIT_LEN_LIMB / NUMBER_OF_LOOKUP_LIMBS
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccMul<E: CurveAffine, C: CurveAffine> {
+        point: Option<Point<C>>,
+        scalar: Option<Integer<C::ScalarExt>>,
+        result: Option<Point<C>>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccMul<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let point = ecc_chip.assign_point(&mut region, self.point.clone(), offset)?;
+                    let scalar = ecc_chip.e_scalar_field.assign_integer(&mut region, self.scalar.clone(), offset)?;
+                    let result_0 = ecc_chip.assign_point(&mut region, self.result.clone(), offset)?;
+                    let result_1 = ecc_chip.mul_var(&mut region, point, scalar, offset)?;
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result_0.x, &result_1.x, offset)?;
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result_0.y, &result_1.y, offset)?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccAdd<E: CurveAffine, C: CurveAffine> {
+        p0: Option<Point<C>>,
+        p1: Option<Point<C>>,
+        result: Option<Point<C>>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccAdd<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let p0 = ecc_chip.assign_point(&mut region, self.p0.clone(), offset)?;
+                    let p1 = ecc_chip.assign_point(&mut region, self.p1.clone(), offset)?;
+                    let result_0 = ecc_chip.assign_point(&mut region, self.result.clone(), offset)?;
+                    let result_1 = ecc_chip.add(&mut region, p0, p1, offset)?;
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result_0.x, &result_1.x, offset)?;
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result_0.y, &result_1.y, offset)?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_add_circuit_foreign_base_field() {
+        // Pallas points are emulated on Pallas scalar field so coordinates
+        // live in a field other than the native one
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EpAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let p0 = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let p1 = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let result = (p0 + p1).to_affine();
+
+        let circuit = TestCircuitEccAdd::<E, C> {
+            p0: Some(Point::new_from_point(p0, NUMBER_OF_LIMBS, bit_len_limb)),
+            p1: Some(Point::new_from_point(p1, NUMBER_OF_LIMBS, bit_len_limb)),
+            result: Some(Point::new_from_point(result, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitGenerator<E: CurveAffine, C: CurveAffine> {
+        generator: Option<Point<C>>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitGenerator<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let generator_0 = ecc_chip.assign_generator(&mut region, offset)?;
+                    let generator_1 = ecc_chip.assign_point(&mut region, self.generator.clone(), offset)?;
+                    ecc_chip.assert_equal(&mut region, &generator_0, &generator_1, offset)?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_assign_generator() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let circuit = TestCircuitGenerator::<E, C> {
+            generator: Some(Point::new_from_point(generator, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base: rns_base.clone(),
+            rns_scalar: rns_scalar.clone(),
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+
+        // any other point is rejected
+        let point = (generator + generator).to_affine();
+        let circuit = TestCircuitGenerator::<E, C> {
+            generator: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_ne!(prover.verify(), Ok(()));
+    }
+
+    // Fixed point of `mul_fix` as it is assigned there
+    #[derive(Clone, Debug)]
+    struct TestCircuitConstantPoint<E: CurveAffine, C: CurveAffine> {
+        point: E,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitConstantPoint<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            self.clone()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    ecc_chip.assign_constant_point(&mut region, self.point, offset)?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_mul_fix_point_is_bound_to_verifying_key() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+        use halo2::plonk::keygen_vk;
+        use halo2::poly::commitment::Params;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+
+        #[cfg(not(feature = "no_lookup"))]
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+        #[cfg(feature = "no_lookup")]
+        let k: u32 = 12;
+
+        let params = Params::<C>::new(k);
+        let generator = <E as PrimeCurveAffine>::generator();
+
+        // a free witness would leave the verifying key independent of the point
+        let vks: Vec<String> = vec![generator, (generator + generator).to_affine()]
+            .into_iter()
+            .map(|point| {
+                let circuit = TestCircuitConstantPoint::<E, C> {
+                    point,
+                    rns_base: rns_base.clone(),
+                    rns_scalar: rns_scalar.clone(),
+                };
+                format!("{:?}", keygen_vk(&params, &circuit).unwrap())
+            })
+            .collect();
+
+        assert_ne!(vks[0], vks[1]);
+    }
+
+    #[test]
+    fn test_ecc_mul_circuit() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+
+        // double and add over 256 bits needs more rows than the lookup table
+        let k: u32 = 19;
+
+        // scalar right below the modulus has all significant bits set
+        let scalar = -<E as CurveAffine>::ScalarExt::one();
+        let point = <E as PrimeCurveAffine>::generator();
+        let result = (point * scalar).to_affine();
+
+        let circuit = TestCircuitEccMul::<E, C> {
+            point: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+            scalar: Some(rns_scalar.new_in_crt(scalar)),
+            result: Some(Point::new_from_point(result, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_ecc_mul_circuit_scalar_wider_than_native() {
+        // Pallas scalar field Fq is wider than the native Vesta scalar field Fp
+        use halo2::pasta::EpAffine as E;
+        use halo2::pasta::EqAffine as C;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let native_modulus = field_modulus::<<C as CurveAffine>::ScalarExt>();
+        assert!(rns_scalar.wrong_modulus > native_modulus);
+
+        // double and add over 256 bits needs more rows than the lookup table
+        let k: u32 = 19;
+
+        // scalar right below the modulus doesn't fit in the native field
+        let scalar = -<E as CurveAffine>::ScalarExt::one();
+        assert!(fe_to_big(scalar) >= native_modulus);
+        let point = <E as PrimeCurveAffine>::generator();
+        let result = (point * scalar).to_affine();
+
+        let circuit = TestCircuitEccMul::<E, C> {
+            point: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+            scalar: Some(rns_scalar.new_in_crt(scalar)),
+            result: Some(Point::new_from_point(result, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitSubgroup<E: CurveAffine, C: CurveAffine> {
+        point: Option<Point<C>>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitSubgroup<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let point = &ecc_chip.assign_point(&mut region, self.point.clone(), offset)?;
+                    ecc_chip.assert_in_subgroup(&mut region, point, offset)?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_assert_in_subgroup() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+
+        // double and add over 256 bits needs more rows than the lookup table
+        let k: u32 = 19;
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let point = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let point = Point::<C>::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb);
+
+        let circuit = TestCircuitSubgroup::<E, C> {
+            point: Some(point),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+
+        // Pasta curves have cofactor one so every point on the curve is in the subgroup and
+        // there is no point to reject here. The negative case needs a curve with a cofactor
+    }
+
+    // Point at infinity is represented with `None`
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccAddDegenerate<E: CurveAffine, C: CurveAffine> {
+        p0: Option<Point<C>>,
+        p1: Option<Point<C>>,
+        result: Option<Point<C>>,
+        dummy: Point<C>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccAddDegenerate<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+
+                    // layout does not depend on whether a point is the point at infinity
+                    let mut assign = |point: &Option<Point<C>>| -> Result<AssignedPoint<C>, Error> {
+                        let is_identity = ecc_chip.main_gate().assign_bit_constant(&mut region, point.is_none(), offset)?;
+                        let point = ecc_chip.assign_point(&mut region, Some(point.clone().unwrap_or(self.dummy.clone())), offset)?;
+                        let identity = ecc_chip.assign_identity(&mut region, offset)?;
+                        ecc_chip.select(&mut region, &is_identity, &identity, &point, offset)
+                    };
+
+                    let p0 = assign(&self.p0)?;
+                    let p1 = assign(&self.p1)?;
+                    let result_0 = assign(&self.result)?;
+                    let result_1 = ecc_chip.add(&mut region, p0, p1, offset)?;
+
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result_0.x, &result_1.x, offset)?;
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result_0.y, &result_1.y, offset)?;
+                    main_gate.assert_equal(&mut region, result_0.z, result_1.z, offset)?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig { cases: 12, max_shrink_iters: 32, ..ProptestConfig::default() })]
+
+        #[test]
+        fn test_ecc_add_degenerate_inputs(case in 0..6usize, s0 in 1..u64::MAX, s1 in 1..u64::MAX) {
+            use halo2::pasta::EpAffine as C;
+            use halo2::pasta::EqAffine as E;
+
+            let bit_len_limb = 64;
+            let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+            let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+            let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+            let generator = <E as PrimeCurveAffine>::generator();
+            let identity = <E as PrimeCurveAffine>::identity();
+            let p = (generator * <E as CurveAffine>::ScalarExt::from_u64(s0)).to_affine();
+            let q = (generator * <E as CurveAffine>::ScalarExt::from_u64(s1)).to_affine();
+
+            let (p0, p1) = match case {
+                0 => (p, q),
+                1 => (p, p),
+                2 => (p, -p),
+                3 => (identity, q),
+                4 => (p, identity),
+                _ => (identity, identity),
+            };
+            let result = (p0.to_curve() + p1).to_affine();
+
+            let to_point = |point: E| -> Option<Point<C>> {
+                if bool::from(point.is_identity()) {
+                    None
+                } else {
+                    Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb))
+                }
+            };
+
+            let circuit = TestCircuitEccAddDegenerate::<E, C> {
+                p0: to_point(p0),
+                p1: to_point(p1),
+                result: to_point(result),
+                dummy: Point::new_from_point(generator, NUMBER_OF_LIMBS, bit_len_limb),
+                rns_base,
+                rns_scalar,
+            };
+
+            let prover = match MockProver::run(k, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+
+            prop_assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccSub<E: CurveAffine, C: CurveAffine> {
+        p0: Option<Point<C>>,
+        p1: Option<Point<C>>,
+        // `None` is the point at infinity
+        result: Option<Point<C>>,
+        dummy: Point<C>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccSub<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let p0 = ecc_chip.assign_point(&mut region, self.p0.clone(), offset)?;
+                    let p1 = ecc_chip.assign_point(&mut region, self.p1.clone(), offset)?;
+
+                    let is_identity = ecc_chip.main_gate().assign_bit_constant(&mut region, self.result.is_none(), offset)?;
+                    let result = ecc_chip.assign_point(&mut region, Some(self.result.clone().unwrap_or(self.dummy.clone())), offset)?;
+                    let identity = ecc_chip.assign_identity(&mut region, offset)?;
+                    let result_0 = ecc_chip.select(&mut region, &is_identity, &identity, &result, offset)?;
+
+                    let p1_neg = ecc_chip._neg(&mut region, &p1, offset)?;
+                    let result_1 = ecc_chip.add(&mut region, p0.clone(), p1_neg, offset)?;
+                    let result_2 = ecc_chip.sub(&mut region, p0, p1, offset)?;
+
+                    for result in vec![result_1, result_2].into_iter() {
+                        ecc_chip.e_base_field.assert_equal(&mut region, &result_0.x, &result.x, offset)?;
+                        ecc_chip.e_base_field.assert_equal(&mut region, &result_0.y, &result.y, offset)?;
+                        main_gate.assert_equal(&mut region, result_0.z.clone(), result.z, offset)?;
+                    }
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_sub_circuit() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let p = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let q = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+
+        // p - q and p - p
+        let results = vec![(q, Some((p.to_curve() - q).to_affine())), (p, None)];
+        for (q, result) in results.into_iter() {
+            let circuit = TestCircuitEccSub::<E, C> {
+                p0: Some(Point::new_from_point(p, NUMBER_OF_LIMBS, bit_len_limb)),
+                p1: Some(Point::new_from_point(q, NUMBER_OF_LIMBS, bit_len_limb)),
+                result: result.map(|result| Point::new_from_point(result, NUMBER_OF_LIMBS, bit_len_limb)),
+                dummy: Point::new_from_point(generator, NUMBER_OF_LIMBS, bit_len_limb),
+                rns_base: rns_base.clone(),
+                rns_scalar: rns_scalar.clone(),
+            };
+
+            let prover = match MockProver::run(k, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+
+            assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccLadder<E: CurveAffine, C: CurveAffine> {
+        point: Option<Point<C>>,
+        // scalar bits below the most significant set bit, most significant first
+        bits: Vec<bool>,
+        // replaces the doubled point as the initial second register
+        r1: Option<Point<C>>,
+        result: Option<Point<C>>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccLadder<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let point = ecc_chip.assign_point(&mut region, self.point.clone(), offset)?;
+                    let result = ecc_chip.assign_point(&mut region, self.result.clone(), offset)?;
+
+                    // most significant bit is consumed by initial registers
+                    let mut r0 = point.clone();
+                    let mut r1 = match self.r1.clone() {
+                        Some(r1) => ecc_chip.assign_point(&mut region, Some(r1), offset)?,
+                        None => ecc_chip._double(&mut region, &point, offset)?,
+                    };
+                    for bit in self.bits.iter() {
+                        let bit = if *bit { C::ScalarExt::one() } else { C::ScalarExt::zero() };
+                        let bit = main_gate.assign_bit(&mut region, Some(bit), offset)?;
+                        let (_r0, _r1) = ecc_chip.ladder_step(&mut region, &r0, &r1, &bit, offset)?;
+                        r0 = _r0;
+                        r1 = _r1;
+                    }
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result.x, &r0.x, offset)?;
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result.y, &r0.y, offset)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_ladder_circuit() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+        use rand::Rng;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+
+        // 16 bit scalar with the most significant bit set
+        let scalar: u64 = rand::thread_rng().gen::<u16>() as u64 | (1 << 15);
+        let bits: Vec<bool> = (0..15).rev().map(|i| (scalar >> i) & 1 == 1).collect();
+
+        // fused step must take fewer rows than a double followed by a complete addition
+        let meta = &mut ConstraintSystem::<<C as CurveAffine>::ScalarExt>::default();
+        let config = TestCircuitEccLadder::<E, C>::configure(meta);
+        let ecc_chip = EccChip::<E, C>::new(config.ecc_config, rns_base.clone(), rns_scalar.clone());
+        let ladder_rows = ecc_chip
+            .count_rows(|ecc_chip, region, offset| {
+                let r0 = ecc_chip.assign_point(region, None, offset)?;
+                let r1 = ecc_chip.assign_point(region, None, offset)?;
+                let bit = ecc_chip.main_gate().assign_bit(region, None, offset)?;
+                ecc_chip.ladder_step(region, &r0, &r1, &bit, offset)
+            })
+            .unwrap();
+        let double_and_add_rows = ecc_chip
+            .count_rows(|ecc_chip, region, offset| {
+                let r0 = ecc_chip.assign_point(region, None, offset)?;
+                let point = ecc_chip.assign_point(region, None, offset)?;
+                let double = ecc_chip.double(region, r0, offset)?;
+                ecc_chip.add(region, double, point, offset)
+            })
+            .unwrap();
+        assert!(ladder_rows < double_and_add_rows);
+
+        // point, result and doubled register are assigned ahead of the steps, each step assigns its bit
+        let circuit_rows = ecc_chip
+            .count_rows(|ecc_chip, region, offset| {
+                let point = ecc_chip.assign_point(region, None, offset)?;
+                let result = ecc_chip.assign_point(region, None, offset)?;
+                let mut r0 = point.clone();
+                let mut r1 = ecc_chip._double(region, &point, offset)?;
+                for _ in bits.iter() {
+                    let bit = ecc_chip.main_gate().assign_bit(region, None, offset)?;
+                    let (_r0, _r1) = ecc_chip.ladder_step(region, &r0, &r1, &bit, offset)?;
+                    r0 = _r0;
+                    r1 = _r1;
+                }
+                ecc_chip.e_base_field.assert_equal(region, &result.x, &r0.x, offset)?;
+                ecc_chip.e_base_field.assert_equal(region, &result.y, &r0.y, offset)
+            })
+            .unwrap();
+        let k = (circuit_rows + 16).next_power_of_two().trailing_zeros();
+        #[cfg(not(feature = "no_lookup"))]
+        let k = std::cmp::max(k, (rns_base.bit_len_lookup + 1) as u32);
+
+        let point = <E as PrimeCurveAffine>::generator();
+        let result = (point * <E as CurveAffine>::ScalarExt::from_u64(scalar)).to_affine();
+
+        let circuit = TestCircuitEccLadder::<E, C> {
+            point: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+            bits,
+            r1: None,
+            result: Some(Point::new_from_point(result, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base: rns_base.clone(),
+            rns_scalar: rns_scalar.clone(),
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+
+        // registers that sum up to the point at infinity are rejected
+        let circuit = TestCircuitEccLadder::<E, C> {
+            point: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+            bits: vec![true],
+            r1: Some(Point::new_from_point(-point, NUMBER_OF_LIMBS, bit_len_limb)),
+            result: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_ne!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccDouble<E: CurveAffine, C: CurveAffine> {
+        point: Option<Point<C>>,
+        result: Option<Point<C>>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccDouble<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let point = ecc_chip.assign_point(&mut region, self.point.clone(), offset)?;
+                    let result_0 = ecc_chip.assign_point(&mut region, self.result.clone(), offset)?;
+                    let result_1 = ecc_chip.double(&mut region, point, offset)?;
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result_0.x, &result_1.x, offset)?;
+                    ecc_chip.e_base_field.assert_equal(&mut region, &result_0.y, &result_1.y, offset)?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_double_circuit_pallas() {
+        // Pallas base field Fp is emulated on native Fq
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EpAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let point = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let result = (point.to_curve() + point).to_affine();
+
+        let circuit = TestCircuitEccDouble::<E, C> {
+            point: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+            result: Some(Point::new_from_point(result, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_ecc_add_circuit_pallas() {
+        // Pallas base field Fp is emulated on native Fq
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EpAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let p0 = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let p1 = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let result = (p0 + p1).to_affine();
+
+        let circuit = TestCircuitEccAdd::<E, C> {
+            p0: Some(Point::new_from_point(p0, NUMBER_OF_LIMBS, bit_len_limb)),
+            p1: Some(Point::new_from_point(p1, NUMBER_OF_LIMBS, bit_len_limb)),
+            result: Some(Point::new_from_point(result, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn test_ecc_mul_circuit_pallas() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EpAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+
+        // double and add over 256 bits needs more rows than the lookup table
+        let k: u32 = 19;
+
+        let scalar = <E as CurveAffine>::ScalarExt::rand();
+        let point = (<E as PrimeCurveAffine>::generator() * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let result = (point * scalar).to_affine();
+
+        let circuit = TestCircuitEccMul::<E, C> {
+            point: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+            scalar: Some(rns_scalar.new_in_crt(scalar)),
+            result: Some(Point::new_from_point(result, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccEqualConstant<E: CurveAffine, C: CurveAffine> {
+        point: Option<Point<C>>,
+        constant: Point<C>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccEqualConstant<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                point: None,
+                constant: self.constant.clone(),
+                rns_base: self.rns_base.clone(),
+                rns_scalar: self.rns_scalar.clone(),
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let point = ecc_chip.assign_point(&mut region, self.point.clone(), offset)?;
+                    ecc_chip.assert_equal_constant(&mut region, &point, self.constant.clone(), offset)?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_assert_equal_constant() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let constant = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let other = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+
+        for (point, expected) in vec![(constant, true), (other, false)].into_iter() {
+            let circuit = TestCircuitEccEqualConstant::<E, C> {
+                point: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+                constant: Point::new_from_point(constant, NUMBER_OF_LIMBS, bit_len_limb),
+                rns_base: rns_base.clone(),
+                rns_scalar: rns_scalar.clone(),
+            };
+
+            let prover = match MockProver::run(k, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+
+            assert_eq!(prover.verify().is_ok(), expected);
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccOnCurve<E: CurveAffine, C: CurveAffine> {
+        points: Vec<Option<Point<C>>>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccOnCurve<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                points: vec![None; self.points.len()],
+                rns_base: self.rns_base.clone(),
+                rns_scalar: self.rns_scalar.clone(),
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let mut points = vec![];
+                    for point in self.points.iter() {
+                        points.push(ecc_chip.assign_point(&mut region, point.clone(), offset)?);
+                    }
+                    ecc_chip.assert_each_on_curve(&mut region, &points, offset)
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_assert_each_on_curve() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let number_of_points = 4;
+
+        // curve constants assigned once must take fewer rows than a check per point
+        let meta = &mut ConstraintSystem::<<C as CurveAffine>::ScalarExt>::default();
+        let config = TestCircuitEccOnCurve::<E, C>::configure(meta);
+        let ecc_chip = EccChip::<E, C>::new(config.ecc_config, rns_base.clone(), rns_scalar.clone());
+        let on_curve_rows = |batch: bool| {
+            ecc_chip
+                .count_rows(|ecc_chip, region, offset| {
+                    let mut points = vec![];
+                    for _ in 0..number_of_points {
+                        points.push(ecc_chip.assign_point(region, None, offset)?);
+                    }
+                    if batch {
+                        ecc_chip.assert_each_on_curve(region, &points, offset)
+                    } else {
+                        points.into_iter().try_for_each(|point| ecc_chip.assert_is_on_curve(region, point, offset))
+                    }
+                })
+                .unwrap()
+        };
+        let (batch_rows, individual_rows) = (on_curve_rows(true), on_curve_rows(false));
+        assert!(batch_rows < individual_rows);
+
+        // leave room for the rows reserved for blinding
+        let k = (batch_rows + 16).next_power_of_two().trailing_zeros();
+        #[cfg(not(feature = "no_lookup"))]
+        let k = std::cmp::max(k, (rns_base.bit_len_lookup + 1) as u32);
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let points: Vec<Point<C>> = (0..number_of_points)
+            .map(|_| {
+                let point = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+                Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)
+            })
+            .collect();
+
+        // x of one point paired with y of another is off the curve
+        let mut off_curve = points.clone();
+        off_curve[2] = Point::new(points[2].x.clone(), points[3].y.clone());
+
+        for (points, expected) in vec![(points, true), (off_curve, false)].into_iter() {
+            let circuit = TestCircuitEccOnCurve::<E, C> {
+                points: points.into_iter().map(Some).collect(),
+                rns_base: rns_base.clone(),
+                rns_scalar: rns_scalar.clone(),
+            };
+
+            let prover = match MockProver::run(k, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+
+            assert_eq!(prover.verify().is_ok(), expected);
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccSelect<E: CurveAffine, C: CurveAffine> {
+        // `None` stands for the point at infinity
+        p0: Option<Point<C>>,
+        p1: Point<C>,
+        cond: bool,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccSelect<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let p0 = match self.p0.as_ref() {
+                        Some(p0) => ecc_chip.assign_point(&mut region, Some(p0.clone()), offset)?,
+                        None => ecc_chip.assign_identity(&mut region, offset)?,
+                    };
+                    let p1 = ecc_chip.assign_point(&mut region, Some(self.p1.clone()), offset)?;
+
+                    let cond = if self.cond { C::ScalarExt::one() } else { C::ScalarExt::zero() };
+                    let cond = main_gate.assign_bit(&mut region, Some(cond), offset)?;
+                    let selected = ecc_chip.select(&mut region, &cond, &p0, &p1, offset)?;
+
+                    let expected = if self.cond { &p0 } else { &p1 };
+                    ecc_chip.assert_equal(&mut region, &selected, expected, offset)?;
+                    main_gate.assert_equal(&mut region, selected.z.clone(), expected.z.clone(), offset)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_select() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let double = (generator.to_curve() + generator).to_affine();
+        let generator = Point::new_from_point(generator, NUMBER_OF_LIMBS, bit_len_limb);
+        let double = Point::new_from_point(double, NUMBER_OF_LIMBS, bit_len_limb);
+
+        for (p0, cond) in vec![(Some(generator.clone()), true), (Some(generator), false), (None, true), (None, false)].into_iter() {
+            let circuit = TestCircuitEccSelect::<E, C> {
+                p0,
+                p1: double.clone(),
+                cond,
+                rns_base: rns_base.clone(),
+                rns_scalar: rns_scalar.clone(),
+            };
+
+            let prover = match MockProver::run(k, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+
+            assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccCanonicalIdentity<E: CurveAffine, C: CurveAffine> {
+        p: Point<C>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccCanonicalIdentity<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let p = ecc_chip.assign_point(&mut region, Some(self.p.clone()), offset)?;
+
+                    // point at infinity as witnessed and as it comes out of `p - p`
+                    let identity_0 = ecc_chip.assign_identity(&mut region, offset)?;
+                    let identity_1 = ecc_chip.sub(&mut region, p.clone(), p.clone(), offset)?;
+                    let identity_0 = ecc_chip.canonicalize_identity(&mut region, &identity_0, offset)?;
+                    let identity_1 = ecc_chip.canonicalize_identity(&mut region, &identity_1, offset)?;
+                    ecc_chip.e_base_field.assert_strict_equal(&mut region, &identity_0.x, &identity_1.x, offset)?;
+                    ecc_chip.e_base_field.assert_strict_equal(&mut region, &identity_0.y, &identity_1.y, offset)?;
+                    ecc_chip.assert_equal(&mut region, &identity_0, &identity_1, offset)?;
+
+                    // other points are left as they are
+                    let canonical = ecc_chip.canonicalize_identity(&mut region, &p, offset)?;
+                    ecc_chip.e_base_field.assert_strict_equal(&mut region, &canonical.x, &p.x, offset)?;
+                    ecc_chip.e_base_field.assert_strict_equal(&mut region, &canonical.y, &p.y, offset)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_canonicalize_identity() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+        let p = (<E as PrimeCurveAffine>::generator() * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+        let circuit = TestCircuitEccCanonicalIdentity::<E, C> {
+            p: Point::new_from_point(p, NUMBER_OF_LIMBS, bit_len_limb),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccNeg<E: CurveAffine, C: CurveAffine> {
+        point: Option<Point<C>>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccNeg<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+            let main_gate = ecc_chip.main_gate();
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let point = ecc_chip.assign_point(&mut region, self.point.clone(), offset)?;
+                    let point_neg = ecc_chip.neg(&mut region, &point, offset)?;
+                    let result = ecc_chip.add(&mut region, point, point_neg, offset)?;
+
+                    let is_identity = ecc_chip.main_gate().assign_bit_constant(&mut region, true, offset)?;
+                    main_gate.assert_equal(&mut region, result.z, is_identity, offset)?;
+
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_neg() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let point = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+
+        let circuit = TestCircuitEccNeg::<E, C> {
+            point: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+            rns_base,
+            rns_scalar,
+        };
+
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEccMulNaf<E: CurveAffine, C: CurveAffine> {
+        point: Option<Point<C>>,
+        scalar: Option<Integer<C::ScalarExt>>,
+        rns_base: Rns<E::Base, C::ScalarExt>,
+        rns_scalar: Rns<E::ScalarExt, C::ScalarExt>,
+    }
+
+    impl<E: CurveAffine, C: CurveAffine> Circuit<C::ScalarExt> for TestCircuitEccMulNaf<E, C> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<C::ScalarExt>) -> Self::Config {
+            let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
+            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+            let integer_chip_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
+            let ecc_config = EccConfig { integer_chip_config };
+            TestCircuitConfig { main_gate_config, ecc_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<C::ScalarExt>) -> Result<(), Error> {
+            let ecc_chip = EccChip::<E, C>::new(config.ecc_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+                    let point = ecc_chip.assign_point(&mut region, self.point.clone(), offset)?;
+                    let scalar = ecc_chip.e_scalar_field.assign_integer(&mut region, self.scalar.clone(), offset)?;
+                    let result_0 = ecc_chip.mul_var(&mut region, point.clone(), scalar.clone(), offset)?;
+                    let result_1 = ecc_chip.mul_naf(&mut region, point, scalar, offset)?;
+                    ecc_chip.assert_equal(&mut region, &result_0, &result_1, offset)?;
+                    Ok(())
+                },
+            )?;
+
+            let range_chip = RangeChip::<C::ScalarExt>::new(config.ecc_config.integer_chip_config.range_config, self.rns_base.bit_len_lookup);
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ecc_mul_naf_circuit() {
+        use halo2::pasta::EpAffine as C;
+        use halo2::pasta::EqAffine as E;
+
+        let bit_len_limb = 64;
+        let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+        let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
+
+        // windowed digits must take fewer rows than an addition per bit
+        let meta = &mut ConstraintSystem::<<C as CurveAffine>::ScalarExt>::default();
+        let config = TestCircuitEccMulNaf::<E, C>::configure(meta);
+        let ecc_chip = EccChip::<E, C>::new(config.ecc_config, rns_base.clone(), rns_scalar.clone());
+        let mul_rows = |mul_naf: bool| {
+            ecc_chip
+                .count_rows(|ecc_chip, region, offset| {
+                    let point = ecc_chip.assign_point(region, None, offset)?;
+                    let scalar = ecc_chip.e_scalar_field.assign_integer(region, None, offset)?;
+                    if mul_naf {
+                        ecc_chip.mul_naf(region, point, scalar, offset)
+                    } else {
+                        ecc_chip.mul_var(region, point, scalar, offset)
+                    }
+                })
+                .unwrap()
+        };
+        let (naf_rows, mul_var_rows) = (mul_rows(true), mul_rows(false));
+        assert!(naf_rows < mul_var_rows);
+
+        // both multiplications are in the circuit, leave room for the rows reserved for blinding
+        let k = (naf_rows + mul_var_rows + 16).next_power_of_two().trailing_zeros();
+        #[cfg(not(feature = "no_lookup"))]
+        let k = std::cmp::max(k, (rns_base.bit_len_lookup + 1) as u32);
+
+        let generator = <E as PrimeCurveAffine>::generator();
+        let point = (generator * <E as CurveAffine>::ScalarExt::rand()).to_affine();
+
+        // even and odd scalars take different paths at the final correction
+        let one = <E as CurveAffine>::ScalarExt::one();
+        let two = <E as CurveAffine>::ScalarExt::from_u64(2);
+        let random = <E as CurveAffine>::ScalarExt::rand();
+        let mut scalars = vec![random * two, random * two + one, -one];
+        scalars.extend((scalars.len()..50).map(|_| <E as CurveAffine>::ScalarExt::rand()));
+
+        for scalar in scalars.into_iter() {
+            let circuit = TestCircuitEccMulNaf::<E, C> {
+                point: Some(Point::new_from_point(point, NUMBER_OF_LIMBS, bit_len_limb)),
+                scalar: Some(rns_scalar.new_in_crt(scalar)),
+                rns_base: rns_base.clone(),
+                rns_scalar: rns_scalar.clone(),
+            };
+
+            let prover = match MockProver::run(k, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+
+            assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use super::{AssignedPoint, EccChip};
+use crate::circuit::integer::IntegerInstructions;
+use crate::circuit::main_gate::MainGateInstructions;
+use halo2::arithmetic::CurveAffine;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+
+impl<E: CurveAffine, C: CurveAffine> EccChip<E, C> {
+    pub(crate) fn _add_incomplete(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        p0: &AssignedPoint<C>,
+        p1: &AssignedPoint<C>,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error> {
+        self.add_incomplete_signed(region, p0, p1, false, offset)
+    }
+
+    fn add_incomplete_signed(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        p0: &AssignedPoint<C>,
+        p1: &AssignedPoint<C>,
+        negate: bool,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error> {
+        let integer_chip = &self.e_base_field;
+
+        // lambda = (y_1 - y_0) / (x_1 - x_0)
+        // x_2 = lambda^2 - x_0 - x_1
+        // y_2 = lambda * (x_0 - x_2) - y_0
+
+        // if p_1 is negated its sign is folded into the slope so that -p_1 is never computed
+        // lambda = (-y_1 - y_0) / (x_1 - x_0) = (y_0 + y_1) / (x_0 - x_1)
+
+        let (numerator, denominator) = if negate {
+            (integer_chip.add(region, &p0.y, &p1.y, offset)?, integer_chip.sub(region, &p0.x, &p1.x, offset)?)
+        } else {
+            (integer_chip.sub(region, &p1.y, &p0.y, offset)?, integer_chip.sub(region, &p1.x, &p0.x, offset)?)
+        };
+        let numerator = &integer_chip.reduce(region, &numerator, offset)?;
+        let denominator = &integer_chip.reduce(region, &denominator, offset)?;
+
+        // denominator is zero only at exceptional cases and result is discarded then,
+        // so slope is not a division which would be unsatisfiable for a zero denominator
+        let (denominator_inv, _) = &integer_chip.invert(region, denominator, offset)?;
+        let lambda = &integer_chip.mul(region, numerator, denominator_inv, offset)?;
+
+        let lambda_square = &integer_chip.square(region, lambda, offset)?;
+        let x_2 = &integer_chip.sub(region, lambda_square, &p0.x, offset)?;
+        let x_2 = &integer_chip.sub(region, x_2, &p1.x, offset)?;
+        let x_2 = integer_chip.reduce(region, x_2, offset)?;
+
+        let t = &integer_chip.sub(region, &p0.x, &x_2, offset)?;
+        let t = &integer_chip.reduce(region, t, offset)?;
+        let y_2 = &integer_chip.mul(region, lambda, t, offset)?;
+        let y_2 = &integer_chip.sub(region, y_2, &p0.y, offset)?;
+        let y_2 = integer_chip.reduce(region, y_2, offset)?;
+
+        let z = self.main_gate().assign_bit_constant(region, false, offset)?;
+
+        Ok(AssignedPoint { x: x_2, y: y_2, z })
+    }
+
+    pub(crate) fn _add(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        p0: &AssignedPoint<C>,
+        p1: &AssignedPoint<C>,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error> {
+        self.add_signed(region, p0, p1, false, offset)
+    }
+
+    pub(crate) fn add_signed(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        p0: &AssignedPoint<C>,
+        p1: &AssignedPoint<C>,
+        negate: bool,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error> {
+        let integer_chip = &self.e_base_field;
+
+        // Exceptional cases are resolved with selections where q is p_1 or -p_1 if negated:
+        // p_0 = O        -> q
+        // q = O          -> p_0
+        // p_0 = q        -> 2 * p_0
+        // p_0 = -q       -> O
+        // otherwise      -> p_0 + q
+
+        let sum = &self.add_incomplete_signed(region, p0, p1, negate, offset)?;
+        let double = &self._double(region, p0, offset)?;
+        let identity = &self.assign_identity(region, offset)?;
+
+        let x_is_equal = &integer_chip.is_equal(region, &p0.x, &p1.x, offset)?;
+        let y_is_equal = &integer_chip.is_equal(region, &p0.y, &p1.y, offset)?;
+
+        // equal y coordinates mean p_0 = -q rather than p_0 = q when p_1 is negated
+        let (same, opposite) = if negate { (identity, double) } else { (double, identity) };
+        let result = &self._select(region, y_is_equal, same, opposite, offset)?;
+        let result = &self._select(region, x_is_equal, result, sum, offset)?;
+        let result = &self._select(region, &p1.z, p0, result, offset)?;
+
+        // -p_1 itself is only needed as the result for p_0 = O
+        let p1_neg;
+        let q = if negate {
+            p1_neg = self._neg(region, p1, offset)?;
+            &p1_neg
+        } else {
+            p1
+        };
+        self._select(region, &p0.z, q, result, offset)
+    }
+}
@@ -0,0 +1,41 @@
+use super::{AssignedPoint, EccChip};
+use crate::circuit::integer::IntegerInstructions;
+use halo2::arithmetic::CurveAffine;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+
+impl<E: CurveAffine, C: CurveAffine> EccChip<E, C> {
+    pub(crate) fn _double(&self, region: &mut Region<'_, C::ScalarExt>, p: &AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
+        let integer_chip = &self.e_base_field;
+
+        // TODO: curves with non zero `a` parameter
+        // lambda = 3 * x^2 / (2 * y)
+        // x_2 = lambda^2 - 2 * x
+        // y_2 = lambda * (x - x_2) - y
+
+        let x_square = &integer_chip.square(region, &p.x, offset)?;
+        let numerator = &integer_chip.mul_constant(region, x_square, big_uint::from(3u64), offset)?;
+        let denominator = &integer_chip.add(region, &p.y, &p.y, offset)?;
+        let denominator = &integer_chip.reduce(region, denominator, offset)?;
+
+        // denominator is zero only if the input is the point at infinity
+        // so slope is not a division which would be unsatisfiable for a zero denominator
+        let (denominator_inv, _) = &integer_chip.invert(region, denominator, offset)?;
+        let lambda = &integer_chip.mul(region, numerator, denominator_inv, offset)?;
+
+        let lambda_square = &integer_chip.square(region, lambda, offset)?;
+        let x_2 = &integer_chip.sub(region, lambda_square, &p.x, offset)?;
+        let x_2 = &integer_chip.sub(region, x_2, &p.x, offset)?;
+        let x_2 = integer_chip.reduce(region, x_2, offset)?;
+
+        let t = &integer_chip.sub(region, &p.x, &x_2, offset)?;
+        let t = &integer_chip.reduce(region, t, offset)?;
+        let y_2 = &integer_chip.mul(region, lambda, t, offset)?;
+        let y_2 = &integer_chip.sub(region, y_2, &p.y, offset)?;
+        let y_2 = integer_chip.reduce(region, y_2, offset)?;
+
+        // doubling the point at infinity results in the point at infinity
+        Ok(AssignedPoint { x: x_2, y: y_2, z: p.z.clone() })
+    }
+}
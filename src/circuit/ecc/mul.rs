@@ -0,0 +1,185 @@
+use super::{AssignedPoint, EccChip};
+use crate::circuit::integer::IntegerInstructions;
+use crate::circuit::main_gate::MainGateInstructions;
+use crate::circuit::{AssignedCondition, AssignedInteger};
+use halo2::arithmetic::CurveAffine;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+
+impl<E: CurveAffine, C: CurveAffine> EccChip<E, C> {
+    pub(crate) fn _mul_var(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        p: &AssignedPoint<C>,
+        e: &AssignedInteger<C::ScalarExt>,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error> {
+        // Scalar is decomposed limb by limb so bits above the native modulus are kept.
+        // It is not required to be reduced since (e + n) * P = e * P
+        let bits = self.e_scalar_field._to_bits(region, e, offset)?;
+
+        // double and add starting from the most significant bit
+        let mut acc = self.assign_identity(region, offset)?;
+        for bit in bits.iter().rev() {
+            acc = self._double(region, &acc, offset)?;
+            let sum = self._add(region, &acc, p, offset)?;
+            acc = self._select(region, bit, &sum, &acc, offset)?;
+        }
+
+        Ok(acc)
+    }
+
+    /// Scalar multiplication over signed odd digits of `window_size` bits
+    pub(crate) fn _mul_naf(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        p: &AssignedPoint<C>,
+        e: &AssignedInteger<C::ScalarExt>,
+        window_size: usize,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error> {
+        assert!(window_size > 0);
+        let main_gate = self.main_gate();
+
+        // For an odd scalar k of n bits
+        // k = 2^(n-1) + sum (2 * k_(i+1) - 1) * 2^i for i in [0, n-1)
+        // so each digit is ±1 and a window of w digits is an odd number in [-(2^w - 1), 2^w - 1].
+        // An even scalar is handled as k + 1 and p is subtracted at the end.
+        let bits = self.e_scalar_field._to_bits(region, e, offset)?;
+        let number_of_digits = bits.len() - 1;
+
+        // odd multiples [p, 3p, 5p, ...]
+        let number_of_multiples = 1 << (window_size - 1);
+        let double = &self._double(region, p, offset)?;
+        let mut table = vec![p.clone()];
+        for i in 1..number_of_multiples {
+            let multiple = self._add(region, &table[i - 1], double, offset)?;
+            table.push(multiple);
+        }
+
+        // windows are processed from the most significant one and the first window takes the remainder
+        let mut window_sizes = vec![];
+        let mut remaining = number_of_digits;
+        while remaining > 0 {
+            let size = if remaining % window_size == 0 { window_size } else { remaining % window_size };
+            window_sizes.push(size);
+            remaining -= size;
+        }
+
+        // leading 2^(n-1) term
+        let mut acc = p.clone();
+        let mut position = number_of_digits;
+        for size in window_sizes.into_iter() {
+            position -= size;
+            for _ in 0..size {
+                acc = self._double(region, &acc, offset)?;
+            }
+
+            // digit bits of the window are k_(i+1) for digits at [position, position + size)
+            let window = &bits[position + 1..position + 1 + size];
+            let sign = &window[size - 1];
+
+            // positive digit 2 * m + 1 is indexed with m = lower bits, negative one with their complement
+            let mut candidates: Vec<AssignedPoint<C>> = table[..1 << (size - 1)].to_vec();
+            for bit in window[..size - 1].iter() {
+                let index_bit = &main_gate.xnor(region, bit, sign, offset)?;
+                let mut next = vec![];
+                for pair in candidates.chunks(2) {
+                    next.push(self._select(region, index_bit, &pair[1], &pair[0], offset)?);
+                }
+                candidates = next;
+            }
+            let selected = &candidates[0];
+            let selected_neg = &self._neg(region, selected, offset)?;
+            let digit_point = &self._select(region, sign, selected, selected_neg, offset)?;
+
+            acc = self._add(region, &acc, digit_point, offset)?;
+        }
+
+        // correct for the forced least significant bit
+        let corrected = &self._sub(region, &acc, p, offset)?;
+        self._select(region, &bits[0], &acc, corrected, offset)
+    }
+
+    /// Montgomery ladder step that keeps `r_1 - r_0` invariant
+    /// `(2 * r_0, r_0 + r_1)` if `bit` is not set and `(r_0 + r_1, 2 * r_1)` otherwise
+    pub(crate) fn _ladder_step(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        r0: &AssignedPoint<C>,
+        r1: &AssignedPoint<C>,
+        bit: &AssignedCondition<C::ScalarExt>,
+        offset: &mut usize,
+    ) -> Result<(AssignedPoint<C>, AssignedPoint<C>), Error> {
+        let main_gate = self.main_gate();
+        let integer_chip = &self.e_base_field;
+
+        // Registers are never the point at infinity
+        main_gate.assert_zero(region, r0.z.clone(), offset)?;
+        main_gate.assert_zero(region, r1.z.clone(), offset)?;
+
+        // Sum is symmetric so only the doubled register is selected
+        let a = &self._select(region, bit, r1, r0, offset)?;
+
+        // lambda_sum = (y_1 - y_0) / (x_1 - x_0)
+        // lambda_double = 3 * x_a^2 / (2 * y_a)
+        let numerator_sum = &integer_chip.sub(region, &r1.y, &r0.y, offset)?;
+        let numerator_sum = &integer_chip.reduce(region, numerator_sum, offset)?;
+        let denominator_sum = &integer_chip.sub(region, &r1.x, &r0.x, offset)?;
+        let denominator_sum = &integer_chip.reduce(region, denominator_sum, offset)?;
+        let x_a_square = &integer_chip.square(region, &a.x, offset)?;
+        let numerator_double = &integer_chip.mul_constant(region, x_a_square, big_uint::from(3u64), offset)?;
+        let denominator_double = &integer_chip.add(region, &a.y, &a.y, offset)?;
+        let denominator_double = &integer_chip.reduce(region, denominator_double, offset)?;
+
+        // Both denominators are inverted with a single inversion of their product.
+        // Product is zero if r_0 = ±r_1, that is also when r_0 + r_1 is the point at infinity,
+        // or if y_a is zero, and such inputs are rejected
+        let product = &integer_chip.mul(region, denominator_sum, denominator_double, offset)?;
+        let (product_inv, is_zero) = integer_chip.invert(region, product, offset)?;
+        main_gate.assert_zero(region, is_zero, offset)?;
+        let denominator_sum_inv = &integer_chip.mul(region, &product_inv, denominator_double, offset)?;
+        let denominator_double_inv = &integer_chip.mul(region, &product_inv, denominator_sum, offset)?;
+        let lambda_sum = &integer_chip.mul(region, numerator_sum, denominator_sum_inv, offset)?;
+        let lambda_double = &integer_chip.mul(region, numerator_double, denominator_double_inv, offset)?;
+
+        // x_2 = lambda^2 - x_0 - x_1
+        // y_2 = lambda * (x_0 - x_2) - y_0
+        let lambda_square = &integer_chip.square(region, lambda_sum, offset)?;
+        let x_sum = &integer_chip.sub(region, lambda_square, &r0.x, offset)?;
+        let x_sum = &integer_chip.sub(region, x_sum, &r1.x, offset)?;
+        let x_sum = integer_chip.reduce(region, x_sum, offset)?;
+        let t = &integer_chip.sub(region, &r0.x, &x_sum, offset)?;
+        let t = &integer_chip.reduce(region, t, offset)?;
+        let y_sum = &integer_chip.mul(region, lambda_sum, t, offset)?;
+        let y_sum = &integer_chip.sub(region, y_sum, &r0.y, offset)?;
+        let y_sum = integer_chip.reduce(region, y_sum, offset)?;
+        let sum = &AssignedPoint {
+            x: x_sum,
+            y: y_sum,
+            z: r0.z.clone(),
+        };
+
+        // x_2 = lambda^2 - 2 * x_a
+        // y_2 = lambda * (x_a - x_2) - y_a
+        let lambda_square = &integer_chip.square(region, lambda_double, offset)?;
+        let x_double = &integer_chip.sub(region, lambda_square, &a.x, offset)?;
+        let x_double = &integer_chip.sub(region, x_double, &a.x, offset)?;
+        let x_double = integer_chip.reduce(region, x_double, offset)?;
+        let t = &integer_chip.sub(region, &a.x, &x_double, offset)?;
+        let t = &integer_chip.reduce(region, t, offset)?;
+        let y_double = &integer_chip.mul(region, lambda_double, t, offset)?;
+        let y_double = &integer_chip.sub(region, y_double, &a.y, offset)?;
+        let y_double = integer_chip.reduce(region, y_double, offset)?;
+        let double = &AssignedPoint {
+            x: x_double,
+            y: y_double,
+            z: r0.z.clone(),
+        };
+
+        let r0 = self._select(region, bit, sum, double, offset)?;
+        let r1 = self._select(region, bit, double, sum, offset)?;
+        Ok((r0, r1))
+    }
+}
@@ -0,0 +1,41 @@
+use super::{AssignedPoint, EccChip};
+use crate::circuit::integer::IntegerInstructions;
+use halo2::arithmetic::CurveAffine;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+
+impl<E: CurveAffine, C: CurveAffine> EccChip<E, C> {
+    pub(crate) fn _assert_each_on_curve(&self, region: &mut Region<'_, C::ScalarExt>, points: &[AssignedPoint<C>], offset: &mut usize) -> Result<(), Error> {
+        let integer_chip = &self.e_base_field;
+
+        // y^2 = x^3 + a * x + b
+
+        // curve constants are assigned once for the whole batch
+        let b = &integer_chip.assign_constant(region, integer_chip.rns.new_in_crt(E::b()), offset)?;
+        let a = if E::a() == E::Base::zero() {
+            None
+        } else {
+            Some(integer_chip.assign_constant(region, integer_chip.rns.new_in_crt(E::a()), offset)?)
+        };
+
+        for point in points.iter() {
+            let y_square = &integer_chip.square(region, &point.y, offset)?;
+            let x_square = &integer_chip.square(region, &point.x, offset)?;
+            let x_cube = &integer_chip.mul(region, x_square, &point.x, offset)?;
+            let rhs = integer_chip.add(region, x_cube, b, offset)?;
+            let rhs = match a.as_ref() {
+                Some(a) => {
+                    let a_mul_x = &integer_chip.mul(region, a, &point.x, offset)?;
+                    integer_chip.add(region, &rhs, a_mul_x, offset)?
+                }
+                None => rhs,
+            };
+
+            // the point at infinity has no affine coordinates so the check is skipped for it
+            let rhs = &integer_chip.cond_select(region, y_square, &rhs, &point.z, offset)?;
+            integer_chip.assert_equal(region, y_square, rhs, offset)?;
+        }
+
+        Ok(())
+    }
+}
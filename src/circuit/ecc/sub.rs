@@ -0,0 +1,31 @@
+use super::{AssignedPoint, EccChip};
+use crate::circuit::integer::IntegerInstructions;
+use crate::rns::Integer;
+use halo2::arithmetic::CurveAffine;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+use num_traits::Zero;
+
+impl<E: CurveAffine, C: CurveAffine> EccChip<E, C> {
+    pub(crate) fn _neg(&self, region: &mut Region<'_, C::ScalarExt>, p: &AssignedPoint<C>, offset: &mut usize) -> Result<AssignedPoint<C>, Error> {
+        let integer_chip = &self.e_base_field;
+
+        let zero: Integer<C::ScalarExt> = integer_chip.rns.new_from_big(big_uint::zero());
+        let zero = &integer_chip.assign_constant(region, zero, offset)?;
+        let y = &integer_chip.sub(region, zero, &p.y, offset)?;
+        let y = integer_chip.reduce(region, y, offset)?;
+
+        Ok(AssignedPoint { x: p.x.clone(), y, z: p.z.clone() })
+    }
+
+    pub(crate) fn _sub(
+        &self,
+        region: &mut Region<'_, C::ScalarExt>,
+        p0: &AssignedPoint<C>,
+        p1: &AssignedPoint<C>,
+        offset: &mut usize,
+    ) -> Result<AssignedPoint<C>, Error> {
+        self.add_signed(region, p0, p1, true, offset)
+    }
+}
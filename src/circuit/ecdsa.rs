@@ -97,9 +97,8 @@ impl<E: CurveAffine, C: CurveAffine> EcdsaChip<E, C> {
 
         // 1. check 0 < r, s < n
 
-        // // since `assert_not_zero` already includes a in-field check, we can just call `assert_not_zero`
-        // scalar_chip.assert_in_field(region, &sig.r, offset)?;
-        // scalar_chip.assert_in_field(region, &sig.s, offset)?;
+        scalar_chip.assert_in_field(region, &sig.r, offset)?;
+        scalar_chip.assert_in_field(region, &sig.s, offset)?;
         scalar_chip.assert_not_zero(region, &sig.r, offset)?;
         scalar_chip.assert_not_zero(region, &sig.s, offset)?;
 
@@ -127,15 +126,15 @@ impl<E: CurveAffine, C: CurveAffine> EcdsaChip<E, C> {
 
 #[cfg(test)]
 mod tests {
-    use crate::NUMBER_OF_LIMBS;
+    use crate::{BIT_LEN_LIMB, NUMBER_OF_LIMBS, NUMBER_OF_LOOKUP_LIMBS};
     use crate::circuit::ecc::EccInstruction;
     use crate::circuit::ecdsa::{
-        AssignedEcdsaSig, AssignedPoint, AssignedPublicKey, EccChip, EccConfig, EcdsaChip, EcdsaConfig, EcdsaSig, IntegerChip, IntegerInstructions, Point,
+        AssignedEcdsaSig, AssignedPublicKey, EccChip, EccConfig, EcdsaChip, EcdsaConfig, EcdsaSig, IntegerChip, IntegerInstructions, Point,
     };
     use crate::circuit::main_gate::MainGate;
     use crate::circuit::range::RangeChip;
     use crate::circuit::range::RangeInstructions;
-    use crate::rns::{Integer, Rns, fe_to_big};
+    use crate::rns::{Common, Integer, Rns, fe_to_big};
     use halo2::arithmetic::{CurveAffine, FieldExt, Field};
     use halo2::circuit::{Chip, Layouter, Region, SimpleFloorPlanner};
     use halo2::dev::MockProver;
@@ -173,7 +172,7 @@ mod tests {
             let overflow_bit_lengths = vec![2, 3];
 
             let main_gate_config = MainGate::<C::ScalarExt>::configure(meta);
-            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths.clone());
+            let range_config = RangeChip::<C::ScalarExt>::configure(meta, &main_gate_config, overflow_bit_lengths.clone(), BIT_LEN_LIMB / NUMBER_OF_LOOKUP_LIMBS);
             let scalar_config = IntegerChip::<E::ScalarExt, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
             let ecc_scalar_config = IntegerChip::<E::Base, C::ScalarExt>::configure(meta, &range_config, &main_gate_config);
             let ecc_chip_config = EccConfig {
@@ -185,12 +184,7 @@ mod tests {
         }
 
         fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<<C as CurveAffine>::ScalarExt>) -> Result<(), Error> {
-            let ecc_base_chip =
-                IntegerChip::<E::Base, C::ScalarExt>::new(config.ecdsa_verify_config.ecc_chip_config.integer_chip_config.clone(), self.rns_base.clone());
-            let ecc_chip = EccChip::<E, C> {
-                config: config.ecdsa_verify_config.ecc_chip_config.clone(),
-                e_base_field: ecc_base_chip,
-            };
+            let ecc_chip = EccChip::<E, C>::new(config.ecdsa_verify_config.ecc_chip_config.clone(), self.rns_base.clone(), self.rns_scalar.clone());
             let scalar_chip = IntegerChip::<E::ScalarExt, C::ScalarExt>::new(config.ecdsa_verify_config.scalar_config.clone(), self.rns_scalar.clone());
 
             let ecdsa_chip = EcdsaChip::<E, C>::new(config.ecdsa_verify_config.clone(), ecc_chip, scalar_chip);
@@ -210,14 +204,8 @@ mod tests {
 
                     // println!("assigned r = {:?}", r_assigned);
 
-                    // TODO: should not do this, instead we should use `assign_point`
-                    let x_assigned = ecdsa_chip.ecc_chip.e_base_field.assign_integer(&mut region, Some(self.pk.x.clone()), offset)?;
-                    let y_assigned = ecdsa_chip.ecc_chip.e_base_field.assign_integer(&mut region, Some(self.pk.y.clone()), offset)?;
                     let pk = AssignedPublicKey {
-                        point: AssignedPoint {
-                            x: x_assigned.clone(),
-                            y: y_assigned.clone(),
-                        },
+                        point: ecdsa_chip.ecc_chip.assign_point(&mut region, Some(self.pk.clone()), offset)?,
                     };
 
                     let msg_hash = ecdsa_chip.scalar_chip.assign_integer(&mut region, self.msg_hash.clone(), offset)?;
@@ -251,10 +239,8 @@ mod tests {
         let rns_base = Rns::<<E as CurveAffine>::Base, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
         let rns_scalar = Rns::<<E as CurveAffine>::ScalarExt, <C as CurveAffine>::ScalarExt>::construct(bit_len_limb);
 
-        #[cfg(not(feature = "no_lookup"))]
-        let k: u32 = (rns_base.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let k: u32 = 8;
+        // two scalar multiplications dominate the row count
+        let k: u32 = 20;
 
         let generator = <E as PrimeCurveAffine>::generator();
         let sk = <E as CurveAffine>::ScalarExt::rand();
@@ -286,7 +272,7 @@ mod tests {
             pk,
             msg_hash,
             rns_base,
-            rns_scalar,
+            rns_scalar: rns_scalar.clone(),
         };
 
         let prover = match MockProver::run(k, &circuit, vec![]) {
@@ -295,5 +281,20 @@ mod tests {
         };
 
         assert_eq!(prover.verify(), Ok(()));
+
+        // testcase: r out of scalar field
+        // r + n is congruent to r so it would pass the final comparison if it were accepted
+        let scalar_modulus = rns_scalar.wrong_modulus.clone();
+        for r in vec![scalar_modulus.clone() + 1usize, integer_r.value() + scalar_modulus] {
+            let mut circuit = circuit.clone();
+            circuit.sig.r = rns_scalar.new_from_big(r);
+
+            let prover = match MockProver::run(k, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+
+            assert_ne!(prover.verify(), Ok(()));
+        }
     }
 }
@@ -1,20 +1,28 @@
 use super::main_gate::MainGate;
-use super::{AssignedCondition, AssignedInteger, UnassignedInteger};
+use super::{AssignedCondition, AssignedInteger, AssignedValue, UnassignedInteger};
 use crate::circuit::main_gate::{MainGateConfig, MainGateInstructions};
 use crate::circuit::range::{RangeChip, RangeConfig};
 use crate::circuit::AssignedLimb;
-use crate::rns::{Integer, Rns};
-use crate::{NUMBER_OF_LIMBS, NUMBER_OF_LOOKUP_LIMBS};
+use crate::rns::{Integer, ReductionContext, Rns};
+use crate::NUMBER_OF_LIMBS;
 use halo2::arithmetic::FieldExt;
+use halo2::circuit::layouter::{RegionLayouter, RegionShape};
 use halo2::circuit::Region;
 use halo2::plonk::{ConstraintSystem, Error};
+use num_bigint::BigUint as big_uint;
+use std::cell::RefCell;
 
 mod add;
+mod assert_equal;
 mod assert_in_field;
+mod assert_less_than;
+mod assert_not_zero;
 mod assert_zero;
 mod assign;
+mod bits;
 mod mul;
 mod reduce;
+mod sqrt;
 mod square;
 mod sub;
 mod invert;
@@ -27,14 +35,56 @@ pub struct IntegerConfig {
     pub main_gate_config: MainGateConfig,
 }
 
+/// Limb maxima of an operation result
+#[derive(Clone, Debug)]
+pub struct AuditEntry {
+    pub operation: &'static str,
+    pub max_vals: Vec<big_uint>,
+}
+
+/// Witness generation of reducing operations without laying out any rows
+pub struct WitnessOnly<'a, W: FieldExt, N: FieldExt> {
+    rns: &'a Rns<W, N>,
+}
+
+impl<'a, W: FieldExt, N: FieldExt> WitnessOnly<'a, W, N> {
+    pub(crate) fn mul_context(&self, a: &Integer<N>, b: &Integer<N>) -> ReductionContext<N> {
+        self.rns.mul(a, b)
+    }
+
+    pub(crate) fn reduce_context(&self, a: &Integer<N>) -> ReductionContext<N> {
+        self.rns.reduce(a)
+    }
+
+    /// Named witness values that `mul` would assign
+    pub fn mul(&self, a: &Integer<N>, b: &Integer<N>) -> Vec<(&'static str, N)> {
+        self.mul_context(a, b).to_assignments()
+    }
+
+    /// Named witness values that `reduce` would assign
+    pub fn reduce(&self, a: &Integer<N>) -> Vec<(&'static str, N)> {
+        self.reduce_context(a).to_assignments()
+    }
+}
+
 pub struct IntegerChip<Wrong: FieldExt, Native: FieldExt> {
     config: IntegerConfig,
     // TODO: is `pub` necessary?
     pub rns: Rns<Wrong, Native>,
+    audit_log: Option<RefCell<Vec<AuditEntry>>>,
+    #[cfg(test)]
+    corrupt_mul_context: Option<fn(&mut ReductionContext<Native>)>,
+    // named values of reduction witness cells in the order operations are laid out
+    #[cfg(test)]
+    assigned_witness: RefCell<Vec<Vec<(&'static str, Native)>>>,
 }
 
 pub trait IntegerInstructions<N: FieldExt> {
     fn assign_integer(&self, region: &mut Region<'_, N>, integer: Option<Integer<N>>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    /// Limbs are not range checked so it is only for integers that are constrained elsewhere
+    fn assign_integer_unsafe(&self, region: &mut Region<'_, N>, integer: Option<Integer<N>>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn assign_constant(&self, region: &mut Region<'_, N>, integer: Integer<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn assign_from_limbs(&self, region: &mut Region<'_, N>, limbs: [AssignedLimb<N>; NUMBER_OF_LIMBS], offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
     fn range_assign_integer(
         &self,
         region: &mut Region<'_, N>,
@@ -42,19 +92,58 @@ pub trait IntegerInstructions<N: FieldExt> {
         most_significant_limb_bit_len: usize,
         offset: &mut usize,
     ) -> Result<AssignedInteger<N>, Error>;
+    fn range_assign_integer_with_limits(
+        &self,
+        region: &mut Region<'_, N>,
+        integer: UnassignedInteger<N>,
+        limb_bit_lens: [usize; NUMBER_OF_LIMBS],
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error>;
     fn add(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn add_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
     fn sub(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn sub_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
     fn mul(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn mul_unchecked(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        b: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error>;
+    fn mul_assert_equal(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        b: &AssignedInteger<N>,
+        expected: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<(), Error>;
+    fn mul_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
     fn square(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
-    fn div(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error>;
+    /// Division by zero is not satisfiable
+    fn div(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
     fn invert(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error>;
+    fn sqrt(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error>;
     fn reduce(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn reduce_if_gt(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, threshold: big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    /// Native field residue of `a` that is constrained to the composition of its limbs
+    fn native(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedValue<N>, Error>;
+    fn to_bits(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<Vec<AssignedCondition<N>>, Error>;
+    fn from_bits(&self, region: &mut Region<'_, N>, bits: &[AssignedCondition<N>], offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn bitwise_xor(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn bitwise_and(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn shl_const(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, k: usize, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn shr_const(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, k: usize, offset: &mut usize) -> Result<AssignedInteger<N>, Error>;
+    fn parity(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedCondition<N>, Error>;
     fn assert_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
     fn assert_strict_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
+    fn assert_equal_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: big_uint, offset: &mut usize) -> Result<(), Error>;
     fn assert_not_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
-    fn is_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
+    fn is_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedCondition<N>, Error>;
     fn assert_not_zero(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
     fn assert_in_field(&self, region: &mut Region<'_, N>, input: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error>;
+    fn assert_less_than_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, bound: &Integer<N>, offset: &mut usize) -> Result<(), Error>;
     fn cond_select(
         &self,
         region: &mut Region<'_, N>,
@@ -67,22 +156,70 @@ pub trait IntegerInstructions<N: FieldExt> {
 
 impl<W: FieldExt, N: FieldExt> IntegerInstructions<N> for IntegerChip<W, N> {
     fn add(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
-        self._add(region, a, b, offset)
+        let result = self._add(region, a, b, offset)?;
+        Ok(self.audit("add", result))
+    }
+
+    fn add_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let result = self._add_constant(region, a, &c, offset)?;
+        Ok(self.audit("add_constant", result))
     }
 
     fn sub(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
-        self._sub(region, a, b, offset)
+        let result = self._sub(region, a, b, offset)?;
+        Ok(self.audit("sub", result))
+    }
+
+    fn sub_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let result = self._sub_constant(region, a, &c, offset)?;
+        Ok(self.audit("sub_constant", result))
     }
 
     fn mul(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
-        self._mul(region, a, b, offset)
+        let a = &self.reduce_if_exceeds_max_operand(region, a, offset)?;
+        let b = &self.reduce_if_exceeds_max_operand(region, b, offset)?;
+        self.mul_unchecked(region, a, b, offset)
+    }
+
+    fn mul_unchecked(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        b: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        let result = self._mul(region, a, b, offset)?;
+        Ok(self.audit("mul", result))
+    }
+
+    fn mul_assert_equal(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        b: &AssignedInteger<N>,
+        expected: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let a = &self.reduce_if_exceeds_max_operand(region, a, offset)?;
+        let b = &self.reduce_if_exceeds_max_operand(region, b, offset)?;
+        self._mul_assert_equal(region, a, b, expected, offset)
+    }
+
+    fn mul_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let a = &self.reduce_if_exceeds_max_operand(region, a, offset)?;
+        let result = self._mul_constant(region, a, &c, offset)?;
+        Ok(self.audit("mul_constant", result))
     }
 
     fn square(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
-        self._square(region, a, offset)
+        let a = &self.reduce_if_exceeds_max_operand(region, a, offset)?;
+        let result = self._square(region, a, offset)?;
+        Ok(self.audit("square", result))
     }
 
-    fn div(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error> {
+    fn div(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let a = &self.reduce_if_exceeds_max_operand(region, a, offset)?;
+        let b = &self.reduce_if_exceeds_max_operand(region, b, offset)?;
         self._div(region, a, b, offset)
     }
 
@@ -90,8 +227,50 @@ impl<W: FieldExt, N: FieldExt> IntegerInstructions<N> for IntegerChip<W, N> {
         self._invert(region, a, offset)
     }
 
+    fn sqrt(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error> {
+        self._sqrt(region, a, offset)
+    }
+
     fn reduce(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
-        self._reduce(region, a, offset)
+        let result = self._reduce(region, a, offset)?;
+        Ok(self.audit("reduce", result))
+    }
+
+    fn reduce_if_gt(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, threshold: big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        self._reduce_if_gt(region, a, &threshold, offset)
+    }
+
+    fn native(&self, _: &mut Region<'_, N>, a: &AssignedInteger<N>, _: &mut usize) -> Result<AssignedValue<N>, Error> {
+        // every assigned integer carries its native value as a cell
+        Ok(a.native())
+    }
+
+    fn to_bits(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<Vec<AssignedCondition<N>>, Error> {
+        self._to_bits(region, a, offset)
+    }
+
+    fn from_bits(&self, region: &mut Region<'_, N>, bits: &[AssignedCondition<N>], offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        self._from_bits(region, bits, offset)
+    }
+
+    fn bitwise_xor(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        self._bitwise_xor(region, a, b, offset)
+    }
+
+    fn bitwise_and(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        self._bitwise_and(region, a, b, offset)
+    }
+
+    fn shl_const(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, k: usize, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        self._shl_const(region, a, k, offset)
+    }
+
+    fn shr_const(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, k: usize, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        self._shr_const(region, a, k, offset)
+    }
+
+    fn parity(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedCondition<N>, Error> {
+        self._parity(region, a, offset)
     }
 
     fn range_assign_integer(
@@ -104,8 +283,30 @@ impl<W: FieldExt, N: FieldExt> IntegerInstructions<N> for IntegerChip<W, N> {
         self._range_assign_integer(region, integer, most_significant_limb_bit_len, offset)
     }
 
+    fn range_assign_integer_with_limits(
+        &self,
+        region: &mut Region<'_, N>,
+        integer: UnassignedInteger<N>,
+        limb_bit_lens: [usize; NUMBER_OF_LIMBS],
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        self._range_assign_integer_with_limits(region, integer, limb_bit_lens, offset)
+    }
+
     fn assign_integer(&self, region: &mut Region<'_, N>, integer: Option<Integer<N>>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
-        self._assign_integer(region, integer, offset)
+        self._range_assign_integer(region, integer.into(), self.rns.bit_len_limb, offset)
+    }
+
+    fn assign_integer_unsafe(&self, region: &mut Region<'_, N>, integer: Option<Integer<N>>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        self._assign_integer_unsafe(region, integer, offset)
+    }
+
+    fn assign_constant(&self, region: &mut Region<'_, N>, integer: Integer<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        self._assign_constant(region, integer, offset)
+    }
+
+    fn assign_from_limbs(&self, region: &mut Region<'_, N>, limbs: [AssignedLimb<N>; NUMBER_OF_LIMBS], offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        self._assign_from_limbs(region, limbs, offset)
     }
 
     fn assert_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
@@ -122,6 +323,10 @@ impl<W: FieldExt, N: FieldExt> IntegerInstructions<N> for IntegerChip<W, N> {
         Ok(())
     }
 
+    fn assert_equal_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: big_uint, offset: &mut usize) -> Result<(), Error> {
+        self._assert_equal_constant(region, a, &c, offset)
+    }
+
     fn assert_not_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
         self.assert_in_field(region, a, offset)?;
         self.assert_in_field(region, b, offset)?;
@@ -132,23 +337,23 @@ impl<W: FieldExt, N: FieldExt> IntegerInstructions<N> for IntegerChip<W, N> {
         Ok(())
     }
 
-    fn is_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
+    fn is_equal(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, b: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedCondition<N>, Error> {
         self.assert_in_field(region, a, offset)?;
         self.assert_in_field(region, b, offset)?;
         let main_gate = self.main_gate();
-        for idx in 0..NUMBER_OF_LIMBS {
-            main_gate.is_equal(region, a.limb(idx), b.limb(idx), offset)?;
+
+        // integers are equal only if all limbs are equal
+        let mut is_equal = main_gate.is_equal(region, a.limb(0), b.limb(0), offset)?;
+        for idx in 1..NUMBER_OF_LIMBS {
+            let is_limb_equal = main_gate.is_equal(region, a.limb(idx), b.limb(idx), offset)?;
+            let product = main_gate.mul(region, is_equal, is_limb_equal, offset)?;
+            is_equal = AssignedCondition::new(product.cell, product.value);
         }
-        Ok(())
+        Ok(is_equal)
     }
 
     fn assert_not_zero(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
-        self.assert_in_field(region, a, offset)?;
-        let main_gate = self.main_gate();
-        for idx in 0..NUMBER_OF_LIMBS {
-            main_gate.assert_not_zero(region, a.limb(idx), offset)?;
-        }
-        Ok(())
+        self._assert_not_zero(region, a, offset)
     }
 
     fn cond_select(
@@ -176,17 +381,95 @@ impl<W: FieldExt, N: FieldExt> IntegerInstructions<N> for IntegerChip<W, N> {
 
         let native_value = main_gate.cond_select(region, a.native(), b.native(), cond, offset)?;
 
-        Ok(AssignedInteger::new(limbs, native_value))
+        Ok(AssignedInteger::new(limbs, native_value, self.rns.bit_len_limb))
     }
 
     fn assert_in_field(&self, region: &mut Region<'_, N>, input: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
         self._assert_in_field(region, input, offset)
     }
+
+    fn assert_less_than_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, bound: &Integer<N>, offset: &mut usize) -> Result<(), Error> {
+        self._assert_less_than_constant(region, a, bound, offset)
+    }
 }
 
 impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
     pub fn new(config: IntegerConfig, rns: Rns<W, N>) -> Self {
-        IntegerChip { config, rns }
+        IntegerChip {
+            config,
+            rns,
+            audit_log: None,
+            #[cfg(test)]
+            corrupt_mul_context: None,
+            #[cfg(test)]
+            assigned_witness: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Runs only the witness computation of operations, useful to profile it apart from the layout
+    pub fn witness_only(&self) -> WitnessOnly<'_, W, N> {
+        WitnessOnly { rns: &self.rns }
+    }
+
+    /// Rows that `op` advances the offset by, assignments go to a region that only records its shape.
+    /// With lookups and 64 bit limbs `range_assign_integer` takes 10 rows, `reduce` takes 25 rows
+    /// and `mul` of range checked operands takes 39 rows
+    pub fn count_rows<R>(&self, op: impl FnOnce(&Self, &mut Region<'_, N>, &mut usize) -> Result<R, Error>) -> Result<usize, Error> {
+        let mut shape = RegionShape::new(0.into());
+        let mut region = Region::from(&mut shape as &mut dyn RegionLayouter<N>);
+        let offset = &mut 0;
+        op(self, &mut region, offset)?;
+        Ok(*offset)
+    }
+
+    /// Starts recording limb maxima of arithmetic results
+    pub fn enable_audit(&mut self) {
+        self.audit_log = Some(RefCell::new(Vec::new()));
+    }
+
+    /// Recorded limb maxima in the order operations are applied, empty if audit is not enabled
+    pub fn audit_log(&self) -> Vec<AuditEntry> {
+        self.audit_log.as_ref().map(|log| log.borrow().clone()).unwrap_or_default()
+    }
+
+    fn audit(&self, operation: &'static str, result: AssignedInteger<N>) -> AssignedInteger<N> {
+        if let Some(log) = self.audit_log.as_ref() {
+            let max_vals = result.max_vals().to_vec();
+            log.borrow_mut().push(AuditEntry { operation, max_vals });
+        }
+        result
+    }
+
+    #[cfg(test)]
+    #[allow(clippy::too_many_arguments)]
+    fn record_witness<Q: super::Assigned<N>>(
+        &self,
+        quotient: &[Q],
+        result: &AssignedInteger<N>,
+        t: &[AssignedValue<N>],
+        u_0: Option<N>,
+        u_1: Option<N>,
+        v_0: &AssignedValue<N>,
+        v_1: &AssignedValue<N>,
+    ) {
+        use super::Assigned;
+        const QUOTIENT: [&str; NUMBER_OF_LIMBS] = ["quotient_0", "quotient_1", "quotient_2", "quotient_3"];
+        const RESULT: [&str; NUMBER_OF_LIMBS] = ["result_0", "result_1", "result_2", "result_3"];
+        const T: [&str; NUMBER_OF_LIMBS] = ["t_0", "t_1", "t_2", "t_3"];
+
+        let mut assignments: Vec<(&'static str, Option<N>)> = Vec::new();
+        match quotient {
+            [quotient] => assignments.push(("quotient", quotient.value())),
+            quotient => assignments.extend(QUOTIENT.iter().zip(quotient.iter()).map(|(name, limb)| (*name, limb.value()))),
+        }
+        assignments.extend(RESULT.iter().zip(result.limbs.iter()).map(|(name, limb)| (*name, limb.value())));
+        assignments.extend(T.iter().zip(t.iter()).map(|(name, t)| (*name, t.value())));
+        assignments.extend(vec![("u_0", u_0), ("u_1", u_1), ("v_0", v_0.value()), ("v_1", v_1.value())]);
+
+        let assignments: Option<Vec<(&'static str, N)>> = assignments.into_iter().map(|(name, value)| value.map(|value| (name, value))).collect();
+        if let Some(assignments) = assignments {
+            self.assigned_witness.borrow_mut().push(assignments);
+        }
     }
 
     pub fn configure(_: &mut ConstraintSystem<N>, range_config: &RangeConfig, main_gate_config: &MainGateConfig) -> IntegerConfig {
@@ -197,11 +480,39 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
     }
 
     pub fn range_chip(&self) -> RangeChip<N> {
-        let bit_len_lookup = self.rns.bit_len_limb / NUMBER_OF_LOOKUP_LIMBS;
-        RangeChip::<N>::new(self.config.range_config.clone(), bit_len_lookup)
+        RangeChip::<N>::new(self.config.range_config.clone(), self.rns.bit_len_lookup)
+    }
+
+    /// Returns bit lengths of overflow tables that range checks of this chip require
+    pub fn overflow_lengths(&self) -> Vec<usize> {
+        let bit_len_lookup = self.rns.bit_len_lookup;
+        let bit_lengths = vec![
+            self.rns.bit_len_limb,
+            self.mul_v0_range_tune(),
+            self.mul_v1_range_tune(),
+            self.mul_quotient_range_tune(),
+            self.mul_result_range_tune(),
+            self.red_v0_range_tune(),
+            self.red_v1_range_tune(),
+            self.red_result_range_tune(),
+            self.red_quotient_range_tune(),
+            self.assert_zero_v0_range_tune(),
+            self.assert_zero_v1_range_tune(),
+            self.assert_zero_quotient_range_tune(),
+            self.inert_inv_range_tune(),
+        ];
+
+        let mut overflow_lengths: Vec<usize> = bit_lengths
+            .iter()
+            .map(|bit_len| bit_len % bit_len_lookup)
+            .filter(|overflow_len| *overflow_len != 0)
+            .collect();
+        overflow_lengths.sort();
+        overflow_lengths.dedup();
+        overflow_lengths
     }
 
-    fn main_gate(&self) -> MainGate<N> {
+    pub(crate) fn main_gate(&self) -> MainGate<N> {
         let main_gate_config = self.config.main_gate_config.clone();
         MainGate::<N>::new(main_gate_config)
     }
@@ -210,14 +521,19 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
 #[cfg(test)]
 mod tests {
     use super::{IntegerChip, IntegerConfig, IntegerInstructions};
-    use crate::circuit::AssignedValue;
-    use crate::circuit::main_gate::{MainGate, MainGateConfig, MainGateInstructions};
+    use crate::circuit::{Assigned, AssignedValue, UnassignedValue};
+    use crate::circuit::main_gate::{MainGate, MainGateColumn, MainGateConfig, MainGateInstructions};
     use crate::circuit::range::{RangeChip, RangeInstructions};
-    use crate::rns::{Integer, Limb, Rns};
-    use halo2::arithmetic::FieldExt;
-    use halo2::circuit::{Layouter, SimpleFloorPlanner};
-    use halo2::dev::MockProver;
+    use crate::circuit::tests::test_k;
+    use crate::rns::{big_to_fe, fe_to_big, Common, Integer, Rns};
+    use crate::{BIT_LEN_LIMB, NUMBER_OF_LIMBS, NUMBER_OF_LOOKUP_LIMBS};
+    use halo2::arithmetic::{CurveAffine, FieldExt};
+    use halo2::circuit::{Layouter, Region, SimpleFloorPlanner};
+    use halo2::dev::{MockProver, VerifyFailure};
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
+    use halo2::poly::commitment::Params;
+    use num_bigint::BigUint as big_uint;
+    use num_traits::{One, Zero};
 
     #[derive(Clone, Debug)]
     struct TestCircuitConfig {
@@ -229,27 +545,34 @@ mod tests {
         fn overflow_bit_lengths() -> Vec<usize> {
             vec![2, 3]
         }
+
+        fn bit_len_lookup() -> usize {
+            BIT_LEN_LIMB / NUMBER_OF_LOOKUP_LIMBS
+        }
     }
 
-    #[derive(Default, Clone, Debug)]
-    struct TestCircuitEquality<W: FieldExt, N: FieldExt> {
-        integer_0: Option<Integer<N>>,
-        integer_1: Option<Integer<N>>,
+    // Circuit with the common test configuration whose only region is laid out by `synthesize`
+    #[derive(Clone)]
+    struct TestCircuit<W: FieldExt, N: FieldExt, S> {
         rns: Rns<W, N>,
+        synthesize: S,
     }
 
-    impl<W: FieldExt, N: FieldExt> Circuit<N> for TestCircuitEquality<W, N> {
+    impl<W: FieldExt, N: FieldExt, S> Circuit<N> for TestCircuit<W, N, S>
+    where
+        S: Fn(&IntegerChip<W, N>, &mut Region<'_, N>, &mut usize) -> Result<(), Error> + Clone,
+    {
         type Config = TestCircuitConfig;
         type FloorPlanner = SimpleFloorPlanner;
 
         fn without_witnesses(&self) -> Self {
-            Self::default()
+            self.clone()
         }
 
         fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
             let main_gate_config = MainGate::<N>::configure(meta);
             let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
-            let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths);
+            let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
             let integer_config = IntegerChip::<W, N>::configure(meta, &range_config, &main_gate_config);
             TestCircuitConfig {
                 integer_config,
@@ -264,15 +587,7 @@ mod tests {
                 || "region 0",
                 |mut region| {
                     let offset = &mut 0;
-                    let integer_0 = &integer_chip.assign_integer(&mut region, self.integer_0.clone(), offset)?;
-                    let integer_1 = &integer_chip.assign_integer(&mut region, self.integer_1.clone(), offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_0, integer_1, offset)?;
-                    let integer_0 = &integer_chip.assign_integer(&mut region, self.integer_0.clone(), offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_0, integer_1, offset)?;
-                    let integer_1 = &integer_chip.assign_integer(&mut region, self.integer_0.clone(), offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_0, integer_1, offset)?;
-                    integer_chip.assert_equal(&mut region, integer_0, integer_1, offset)?;
-                    Ok(())
+                    (self.synthesize)(&integer_chip, &mut region, offset)
                 },
             )?;
 
@@ -286,103 +601,88 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_equality_circuit() {
-        use halo2::pasta::Fp as Wrong;
-        use halo2::pasta::Fq as Native;
-        let bit_len_limb = 64;
-
-        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
-
-        #[cfg(not(feature = "no_lookup"))]
-        let k: u32 = (rns.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let k: u32 = 8;
-
-        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
-        let integer_0 = rns.rand_prenormalized();
-        let integer_1 = integer_0.clone();
-
-        let circuit = TestCircuitEquality::<Wrong, Native> {
-            integer_0: Some(integer_0),
-            integer_1: Some(integer_1),
-            rns: rns.clone(),
-        };
+    fn run_test_circuit<W: FieldExt, N: FieldExt>(
+        rns: &Rns<W, N>,
+        synthesize: impl Fn(&IntegerChip<W, N>, &mut Region<'_, N>, &mut usize) -> Result<(), Error> + Clone,
+    ) -> Result<(), Vec<VerifyFailure>> {
+        let k = test_k(&test_integer_chip(rns), rns.bit_len_lookup, synthesize.clone());
 
+        let circuit = TestCircuit { rns: rns.clone(), synthesize };
         let prover = match MockProver::run(k, &circuit, vec![]) {
             Ok(prover) => prover,
             Err(e) => panic!("{:#?}", e),
         };
+        prover.verify()
+    }
 
-        assert_eq!(prover.verify(), Ok(()));
-
-        let integer_0 = rns.rand_prenormalized();
-        let integer_1 = rns.rand_prenormalized();
-
-        let circuit = TestCircuitEquality::<Wrong, Native> {
-            integer_0: Some(integer_0),
-            integer_1: Some(integer_1),
+    // Runs the prover and the verifier with keys generated from `keygen_synthesize` and the
+    // witness of `synthesize`. Prover takes fixed values from the keys, not from `synthesize`
+    fn prove_with_keys_of<W: FieldExt, C: CurveAffine>(
+        params: &Params<C>,
+        rns: &Rns<W, C::ScalarExt>,
+        keygen_synthesize: impl Fn(&IntegerChip<W, C::ScalarExt>, &mut Region<'_, C::ScalarExt>, &mut usize) -> Result<(), Error> + Clone,
+        synthesize: impl Fn(&IntegerChip<W, C::ScalarExt>, &mut Region<'_, C::ScalarExt>, &mut usize) -> Result<(), Error> + Clone,
+    ) -> bool {
+        use halo2::plonk::{create_proof, keygen_pk, keygen_vk, verify_proof};
+        use halo2::transcript::{Blake2bRead, Blake2bWrite, Challenge255};
+
+        let circuit = TestCircuit {
             rns: rns.clone(),
+            synthesize: keygen_synthesize,
         };
-
-        let prover = match MockProver::run(k, &circuit, vec![]) {
-            Ok(prover) => prover,
-            Err(e) => panic!("{:#?}", e),
-        };
-        assert_ne!(prover.verify(), Ok(()));
+        let vk = keygen_vk(params, &circuit).unwrap();
+        let pk = keygen_pk(params, vk, &circuit).unwrap();
+
+        let circuit = TestCircuit { rns: rns.clone(), synthesize };
+        let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+        create_proof(params, &pk, &[circuit], &[&[]], &mut transcript).unwrap();
+        let proof = transcript.finalize();
+
+        let msm = params.empty_msm();
+        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(&proof[..]);
+        match verify_proof(params, pk.get_vk(), msm, &[&[]], &mut transcript) {
+            Ok(guard) => guard.use_challenges().eval(),
+            Err(_) => false,
+        }
     }
 
-    #[derive(Default, Clone, Debug)]
-    struct TestCircuitReduction<W: FieldExt, N: FieldExt> {
-        integer_overflows: Option<Integer<N>>,
-        integer_reduced: Option<Integer<N>>,
-        rns: Rns<W, N>,
+    // Integer chip in the common test configuration to lay out operations outside of a circuit
+    fn test_integer_chip<W: FieldExt, N: FieldExt>(rns: &Rns<W, N>) -> IntegerChip<W, N> {
+        let meta = &mut ConstraintSystem::<N>::default();
+        let main_gate_config = MainGate::<N>::configure(meta);
+        let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
+        let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths, TestCircuitConfig::bit_len_lookup());
+        let integer_config = IntegerChip::<W, N>::configure(meta, &range_config, &main_gate_config);
+        IntegerChip::<W, N>::new(integer_config, rns.clone())
     }
 
-    impl<W: FieldExt, N: FieldExt> Circuit<N> for TestCircuitReduction<W, N> {
-        type Config = TestCircuitConfig;
-        type FloorPlanner = SimpleFloorPlanner;
-
-        fn without_witnesses(&self) -> Self {
-            Self::default()
-        }
-
-        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
-            let main_gate_config = MainGate::<N>::configure(meta);
-            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
-            let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths);
-            let integer_config = IntegerChip::<W, N>::configure(meta, &range_config, &main_gate_config);
-            TestCircuitConfig {
-                integer_config,
-                main_gate_config,
-            }
-        }
-
-        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
-            let integer_chip = IntegerChip::<W, N>::new(config.integer_config.clone(), self.rns.clone());
+    #[test]
+    fn test_equality_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
 
-            layouter.assign_region(
-                || "region 0",
-                |mut region| {
-                    let offset = &mut 0;
-                    let integer_overflows_0 = &integer_chip.assign_integer(&mut region, self.integer_overflows.clone(), offset)?;
-                    let integer_overflows_1 = &integer_overflows_0.clone();
-                    let integer_reduced_0 = &integer_chip.assign_integer(&mut region, self.integer_reduced.clone(), offset)?;
-                    let integer_reduced_1 = &integer_chip.reduce(&mut region, integer_overflows_0, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_reduced_0, integer_reduced_1, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_overflows_0, integer_overflows_1, offset)?;
-
-                    Ok(())
-                },
-            )?;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-            let range_chip = RangeChip::<N>::new(config.integer_config.range_config, self.rns.bit_len_lookup);
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_limb_range_table(&mut layouter)?;
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_overflow_range_tables(&mut layouter)?;
+        let integer_0 = rns.rand_prenormalized();
+        let integer_1 = rns.rand_prenormalized();
 
-            Ok(())
+        for (integer_1, expected) in vec![(integer_0.clone(), true), (integer_1, false)] {
+            let (integer_0, integer_1) = (Some(integer_0.clone()), Some(integer_1));
+            let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let a = &integer_chip.assign_integer(region, integer_0.clone(), offset)?;
+                let b = &integer_chip.assign_integer(region, integer_1.clone(), offset)?;
+                integer_chip.assert_strict_equal(region, a, b, offset)?;
+                let a = &integer_chip.assign_integer(region, integer_0.clone(), offset)?;
+                integer_chip.assert_strict_equal(region, a, b, offset)?;
+                let b = &integer_chip.assign_integer(region, integer_0.clone(), offset)?;
+                integer_chip.assert_strict_equal(region, a, b, offset)?;
+                integer_chip.assert_equal(region, a, b, offset)?;
+
+                Ok(())
+            });
+
+            assert_eq!(result.is_ok(), expected);
         }
     }
 
@@ -395,11 +695,6 @@ mod tests {
 
         let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        #[cfg(not(feature = "no_lookup"))]
-        let k: u32 = (rns.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let k: u32 = 8;
-
         // let input = vec![
         //     "1dfce0ed73516265cde2b9496841f18c",
         //     "e56d9ccbbd4467843028fd719fd3e5a",
@@ -412,305 +707,1159 @@ mod tests {
 
         let integer_reduced = rns.reduce(&integer_overflows).result;
 
-        let circuit = TestCircuitReduction::<Wrong, Native> {
-            integer_overflows: Some(integer_overflows),
-            integer_reduced: Some(integer_reduced),
-            rns: rns.clone(),
-        };
-
-        let prover = match MockProver::run(k, &circuit, vec![]) {
-            Ok(prover) => prover,
-            Err(e) => panic!("{:#?}", e),
-        };
+        let (integer_overflows, integer_reduced) = (Some(integer_overflows), Some(integer_reduced));
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            // limbs are wider than a range checked limb
+            let integer_overflows = &integer_chip.assign_integer_unsafe(region, integer_overflows.clone(), offset)?;
+            let integer_reduced_0 = &integer_chip.assign_integer(region, integer_reduced.clone(), offset)?;
+            let integer_reduced_1 = &integer_chip.reduce(region, integer_overflows, offset)?;
+            integer_chip.assert_strict_equal(region, integer_reduced_0, integer_reduced_1, offset)
+        });
 
-        assert_eq!(prover.verify(), Ok(()));
+        assert_eq!(result, Ok(()));
     }
 
-    #[derive(Default, Clone, Debug)]
-    struct TestCircuitMultiplication<W: FieldExt, N: FieldExt> {
+    // Assigns the operands and the expected product of a multiplication
+    fn mul_synthesize<W: FieldExt, N: FieldExt>(
         integer_a: Option<Integer<N>>,
         integer_b: Option<Integer<N>>,
         integer_c: Option<Integer<N>>,
-        rns: Rns<W, N>,
+    ) -> impl Fn(&IntegerChip<W, N>, &mut Region<'_, N>, &mut usize) -> Result<(), Error> + Clone {
+        move |integer_chip, region, offset| {
+            let integer_a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let integer_b = &integer_chip.assign_integer(region, integer_b.clone(), offset)?;
+            let integer_c_0 = &integer_chip.assign_integer(region, integer_c.clone(), offset)?;
+            let integer_c_1 = &integer_chip.mul(region, integer_a, integer_b, offset)?;
+            integer_chip.assert_strict_equal(region, integer_c_0, integer_c_1, offset)
+        }
     }
 
-    impl<W: FieldExt, N: FieldExt> Circuit<N> for TestCircuitMultiplication<W, N> {
-        type Config = TestCircuitConfig;
-        type FloorPlanner = SimpleFloorPlanner;
-
-        fn without_witnesses(&self) -> Self {
-            Self::default()
-        }
+    #[test]
+    fn test_multiplication_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
-            let main_gate_config = MainGate::<N>::configure(meta);
-            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
-            let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths);
-            let integer_config = IntegerChip::<W, N>::configure(meta, &range_config, &main_gate_config);
-            TestCircuitConfig {
-                integer_config,
-                main_gate_config,
-            }
-        }
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
-            let integer_chip = IntegerChip::<W, N>::new(config.integer_config.clone(), self.rns.clone());
+        let integer_a = rns.rand_prenormalized();
+        let integer_b = rns.rand_prenormalized();
 
-            layouter.assign_region(
-                || "region 0",
-                |mut region| {
-                    let offset = &mut 0;
-                    let integer_a_0 = &integer_chip.assign_integer(&mut region, self.integer_a.clone(), offset)?.clone();
-                    let integer_b_0 = &integer_chip.assign_integer(&mut region, self.integer_b.clone(), offset)?.clone();
-                    let integer_c_0 = &integer_chip.assign_integer(&mut region, self.integer_c.clone(), offset)?.clone();
-                    let integer_a_1 = &integer_a_0.clone();
-                    let integer_b_1 = &integer_b_0.clone();
-                    let integer_c_1 = &integer_chip.mul(&mut region, integer_a_0, integer_b_0, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_c_0, integer_c_1, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_a_0, integer_a_1, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_b_0, integer_b_1, offset)?;
-
-                    Ok(())
-                },
-            )?;
+        let integer_c = rns.mul(&integer_a, &integer_b).result;
 
-            let range_chip = RangeChip::<N>::new(config.integer_config.range_config, self.rns.bit_len_lookup);
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_limb_range_table(&mut layouter)?;
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_overflow_range_tables(&mut layouter)?;
+        let result = run_test_circuit(&rns, mul_synthesize(Some(integer_a), Some(integer_b), Some(integer_c)));
 
-            Ok(())
-        }
+        assert_eq!(result, Ok(()));
     }
 
     #[test]
-    fn test_multiplication_circuit() {
+    fn test_keygen_without_witnesses() {
+        use halo2::pasta::EpAffine;
         use halo2::pasta::Fp as Wrong;
         use halo2::pasta::Fq as Native;
+        use halo2::plonk::{keygen_pk, keygen_vk};
 
         let bit_len_limb = 64;
         let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        #[cfg(not(feature = "no_lookup"))]
-        let k: u32 = (rns.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let k: u32 = 8;
+        // key generation synthesizes the circuit with no witness values
+        let synthesize = mul_synthesize::<Wrong, Native>(None, None, None);
+        let k = test_k(&test_integer_chip(&rns), rns.bit_len_lookup, synthesize.clone());
+        let circuit = TestCircuit { rns, synthesize };
+
+        let params = Params::<EpAffine>::new(k);
+        let vk = keygen_vk(&params, &circuit).unwrap();
+        keygen_pk(&params, vk, &circuit).unwrap();
+    }
+
+    #[test]
+    fn test_mul_assert_equal_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
         let integer_a = rns.rand_prenormalized();
         let integer_b = rns.rand_prenormalized();
-
         let integer_c = rns.mul(&integer_a, &integer_b).result;
+        let integer_c_wrong = rns.new_from_big(rns.value(&integer_c) + 1usize);
+
+        for (integer_c, expected) in vec![(integer_c, true), (integer_c_wrong, false)] {
+            let integer_a = Some(integer_a.clone());
+            let integer_b = Some(integer_b.clone());
+            let integer_c = Some(integer_c);
+            let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let integer_a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+                let integer_b = &integer_chip.assign_integer(region, integer_b.clone(), offset)?;
+                let integer_c = &integer_chip.assign_integer(region, integer_c.clone(), offset)?;
+                integer_chip.mul_assert_equal(region, integer_a, integer_b, integer_c, offset)?;
+
+                Ok(())
+            });
+
+            assert_eq!(result.is_ok(), expected);
+        }
+    }
 
-        let circuit = TestCircuitMultiplication::<Wrong, Native> {
-            integer_a: Some(integer_a),
-            integer_b: Some(integer_b),
-            integer_c: Some(integer_c),
-            rns: rns.clone(),
-        };
+    #[test]
+    fn test_assert_equal_constant_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-        let prover = match MockProver::run(k, &circuit, vec![]) {
-            Ok(prover) => prover,
-            Err(e) => panic!("{:#?}", e),
-        };
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        assert_eq!(prover.verify(), Ok(()));
+        let integer = rns.rand_normalized();
+        let value = integer.value();
+
+        // constant is compared modulo the wrong modulus
+        let constants = vec![
+            (value.clone(), true),
+            (value.clone() + rns.wrong_modulus.clone(), true),
+            (value + 1usize, false),
+        ];
+
+        for (constant, expected) in constants {
+            let integer = Some(integer.clone());
+            let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let a = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+                integer_chip.assert_equal_constant(region, a, constant.clone(), offset)?;
+
+                Ok(())
+            });
+
+            assert_eq!(result.is_ok(), expected);
+        }
     }
 
-    #[derive(Default, Clone, Debug)]
-    struct TestCircuitSquaring<W: FieldExt, N: FieldExt> {
-        integer_a: Option<Integer<N>>,
+    #[test]
+    fn test_sub_constant_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let wrong_modulus = rns.wrong_modulus.clone();
+        let y = rns.rand_normalized().value();
+        let zero = rns.new_from_big(big_uint::zero());
+
+        // negation is subtraction of the constant from zero
+        let cases = vec![
+            (zero, y.clone()),
+            (rns.rand_normalized(), y.clone()),
+            (rns.rand_normalized(), big_uint::zero()),
+            (rns.rand_normalized(), wrong_modulus.clone() - 1usize),
+            (rns.rand_normalized(), y + wrong_modulus.clone()),
+        ];
+
+        for (integer, constant) in cases {
+            let expected = (integer.value() + wrong_modulus.clone() - (constant.clone() % wrong_modulus.clone())) % wrong_modulus.clone();
+            let (integer, expected) = (Some(integer), Some(rns.new_from_big(expected)));
+            let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let a = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+                let expected = &integer_chip.assign_integer(region, expected.clone(), offset)?;
+                let c = &integer_chip.sub_constant(region, a, constant.clone(), offset)?;
+                if let Some(integer) = c.integer() {
+                    for (limb, max_val) in integer.limbs().into_iter().zip(c.max_vals().iter()) {
+                        assert!(fe_to_big(limb) <= *max_val);
+                    }
+                }
+                integer_chip.assert_equal(region, c, expected, offset)?;
+
+                Ok(())
+            });
+
+            assert_eq!(result, Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_add_constant_circuit() {
+        use halo2::arithmetic::CurveAffine;
+        use halo2::pasta::EpAffine;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let wrong_modulus = rns.wrong_modulus.clone();
+        // `b` of the on curve equation y^2 = x^3 + a*x + b
+        let b = fe_to_big(EpAffine::b());
+
+        let cases = vec![
+            (rns.rand_normalized(), b),
+            (rns.new_from_big(wrong_modulus.clone() - 1usize), big_uint::one()),
+            (rns.rand_normalized(), big_uint::zero()),
+            (rns.rand_normalized(), wrong_modulus.clone() - 1usize),
+            (rns.rand_normalized(), rns.rand_normalized().value() + wrong_modulus.clone()),
+        ];
+
+        for (integer, constant) in cases {
+            let expected = (integer.value() + constant.clone()) % wrong_modulus.clone();
+            let (integer, expected) = (Some(integer), Some(rns.new_from_big(expected)));
+            let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let a = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+                let expected = &integer_chip.assign_integer(region, expected.clone(), offset)?;
+                let c = &integer_chip.add_constant(region, a, constant.clone(), offset)?;
+                if let Some(integer) = c.integer() {
+                    for (limb, max_val) in integer.limbs().into_iter().zip(c.max_vals().iter()) {
+                        assert!(fe_to_big(limb) <= *max_val);
+                    }
+                }
+                integer_chip.assert_equal(region, c, expected, offset)?;
+
+                Ok(())
+            });
+
+            assert_eq!(result, Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_squaring_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_a = rns.rand_prenormalized();
+
+        let integer_c = rns.mul(&integer_a, &integer_a).result;
+
+        let (integer_a, integer_c) = (Some(integer_a), Some(integer_c));
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let integer_a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let integer_c_0 = &integer_chip.assign_integer(region, integer_c.clone(), offset)?;
+            let integer_c_1 = &integer_chip.square(region, integer_a, offset)?;
+            integer_chip.assert_strict_equal(region, integer_c_0, integer_c_1, offset)
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_assert_in_field_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+
+        let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // both p and p + 1 must be rejected
+        let wrong_modulus = rns.wrong_modulus.clone();
+        let inputs = vec![
+            (rns.wrong_modulus_minus_one.clone().into(), true),
+            (rns.rand_normalized(), true),
+            (rns.new_from_big(wrong_modulus.clone()), false),
+            (rns.new_from_big(wrong_modulus + 1usize), false),
+        ];
+
+        for (input, expected) in inputs.into_iter() {
+            let input = Some(input);
+            let result = run_test_circuit(rns, move |integer_chip, region, offset| {
+                let integer = &integer_chip.assign_integer(region, input.clone(), offset)?;
+                integer_chip.assert_in_field(region, integer, offset)
+            });
+
+            assert_eq!(result.is_ok(), expected);
+        }
+    }
+
+    fn run_invert_circuit<W: FieldExt, N: FieldExt>(
+        rns: &Rns<W, N>,
+        integer_a: Integer<N>,
+        integer_b: Option<Integer<N>>,
+        cond: N,
+    ) -> Result<(), Vec<VerifyFailure>> {
+        let integer_a = Some(integer_a);
+        run_test_circuit(rns, move |integer_chip, region, offset| {
+            let integer_a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let integer_b_0 = &integer_chip.assign_integer(region, integer_b.clone(), offset)?;
+            let cond_0 = integer_chip.main_gate().assign_bit(region, Some(cond), offset)?;
+            let (integer_b_1, cond_1) = &integer_chip.invert(region, integer_a, offset)?;
+            integer_chip.assert_strict_equal(region, integer_b_0, integer_b_1, offset)?;
+            integer_chip.main_gate().assert_equal(region, cond_0, cond_1.clone(), offset)?;
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_invert_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_a_cand = rns.rand_prenormalized();
+        let integer_a =
+            if rns.value(&integer_a_cand) % &rns.wrong_modulus == 0u32.into() {
+                rns.new_from_big(1u32.into())
+            } else {
+                integer_a_cand
+            };
+        let integer_b = rns.invert(&integer_a);
+
+        assert_eq!(run_invert_circuit(&rns, integer_a, integer_b, Native::zero()), Ok(()));
+    }
+
+    #[test]
+    fn test_zero_invert_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_a = rns.new_from_big(0u32.into());
+        let integer_b = rns.new_from_big(1u32.into());
+
+        assert_eq!(run_invert_circuit(&rns, integer_a, Some(integer_b), Native::one()), Ok(()));
+    }
+
+    fn run_division_circuit<W: FieldExt, N: FieldExt>(
+        rns: &Rns<W, N>,
+        integer_a: Integer<N>,
+        integer_b: Integer<N>,
         integer_c: Option<Integer<N>>,
-        rns: Rns<W, N>,
+    ) -> Result<(), Vec<VerifyFailure>> {
+        let (integer_a, integer_b) = (Some(integer_a), Some(integer_b));
+        run_test_circuit(rns, move |integer_chip, region, offset| {
+            let integer_a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let integer_b = &integer_chip.assign_integer(region, integer_b.clone(), offset)?;
+            let integer_c_0 = &integer_chip.assign_integer(region, integer_c.clone(), offset)?;
+            let integer_c_1 = &integer_chip.div(region, integer_a, integer_b, offset)?;
+            integer_chip.assert_equal(region, integer_c_0, integer_c_1, offset)
+        })
     }
 
-    impl<W: FieldExt, N: FieldExt> Circuit<N> for TestCircuitSquaring<W, N> {
-        type Config = TestCircuitConfig;
-        type FloorPlanner = SimpleFloorPlanner;
+    #[test]
+    fn test_division_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-        fn without_witnesses(&self) -> Self {
-            Self::default()
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_a = rns.rand_prenormalized();
+        let integer_b_cand = rns.rand_prenormalized();
+        let integer_b =
+            if rns.value(&integer_b_cand) % &rns.wrong_modulus == 0u32.into() {
+                rns.new_from_big(1u32.into())
+            } else {
+                integer_b_cand
+            };
+        let integer_c = rns.div(&integer_a, &integer_b);
+
+        assert_eq!(run_division_circuit(&rns, integer_a, integer_b, integer_c), Ok(()));
+    }
+
+    #[test]
+    fn test_division_matches_rns() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        for _ in 0..3 {
+            let integer_a = rns.rand_normalized();
+            let integer_b = rns.rand_normalized();
+            let integer_c = rns.div(&integer_a, &integer_b);
+
+            assert_eq!(run_division_circuit(&rns, integer_a, integer_b, integer_c), Ok(()));
         }
+    }
 
-        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
-            let main_gate_config = MainGate::<N>::configure(meta);
-            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
-            let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths);
-            let integer_config = IntegerChip::<W, N>::configure(meta, &range_config, &main_gate_config);
-            TestCircuitConfig {
-                integer_config,
-                main_gate_config,
+    #[test]
+    fn test_zero_division_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // there is no quotient to claim for a zero divisor, zero and p are both rejected
+        let integer_a = rns.rand_prenormalized();
+        for integer_b in vec![rns.new_from_big(0u32.into()), rns.new_from_big(rns.wrong_modulus.clone())].into_iter() {
+            let integer_c = Some(rns.new_from_big(0u32.into()));
+            assert_ne!(run_division_circuit(&rns, integer_a.clone(), integer_b, integer_c), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_constant_circuit() {
+        use halo2::pasta::EpAffine;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let run = |constant: Integer<Native>, integer: Integer<Native>| {
+            let integer = Some(integer);
+            run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let constant = &integer_chip.assign_constant(region, constant.clone(), offset)?;
+                let integer = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+                integer_chip.assert_strict_equal(region, constant, integer, offset)
+            })
+        };
+
+        let constant = rns.rand_normalized();
+        assert_eq!(run(constant.clone(), constant.clone()), Ok(()));
+
+        // a constant cannot be bound to a different witness
+        let mut limbs = constant.limbs();
+        limbs[0] = limbs[0] + Native::one();
+        assert_ne!(run(constant.clone(), rns.new_from_limbs(limbs)), Ok(()));
+
+        // keys fix the constant, a prover laying out another constant is rejected
+        let layout = |constant: Integer<Native>| {
+            move |integer_chip: &IntegerChip<Wrong, Native>, region: &mut Region<'_, Native>, offset: &mut usize| -> Result<(), Error> {
+                let integer = &integer_chip.assign_integer(region, Some(constant.clone()), offset)?;
+                let constant = &integer_chip.assign_constant(region, constant.clone(), offset)?;
+                integer_chip.assert_strict_equal(region, constant, integer, offset)
+            }
+        };
+        let params = Params::<EpAffine>::new(test_k(&test_integer_chip(&rns), rns.bit_len_lookup, layout(constant.clone())));
+        let other = rns.rand_normalized();
+        assert!(prove_with_keys_of(&params, &rns, layout(constant.clone()), layout(constant.clone())));
+        assert!(!prove_with_keys_of(&params, &rns, layout(constant), layout(other)));
+    }
+
+    #[test]
+    fn test_less_than_constant_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let half = rns.wrong_modulus.clone() >> 1usize;
+        let bound = rns.new_from_big(half.clone());
+
+        let run = |integer: big_uint, bound: Integer<Native>| {
+            let integer = Some(rns.new_from_big(integer));
+            run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let integer = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+                integer_chip.assert_less_than_constant(region, integer, &bound, offset)?;
+                Ok(())
+            })
+        };
+
+        // s < n/2
+        assert_eq!(run(big_uint::from(0u64), bound.clone()), Ok(()));
+        assert_eq!(run(half.clone() >> 1usize, bound.clone()), Ok(()));
+        assert_eq!(run(half.clone() - 1usize, bound.clone()), Ok(()));
+
+        // s >= n/2
+        assert_ne!(run(half.clone(), bound.clone()), Ok(()));
+        assert_ne!(run(half.clone() + 1usize, bound.clone()), Ok(()));
+        assert_ne!(run(rns.wrong_modulus.clone() - 1usize, bound.clone()), Ok(()));
+
+        // nothing is less than zero
+        let zero = rns.new_from_big(big_uint::from(0u64));
+        assert_ne!(run(big_uint::from(0u64), zero.clone()), Ok(()));
+        assert_ne!(run(half, zero), Ok(()));
+    }
+
+    #[test]
+    fn test_assign_from_limbs_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let run = |integer: Integer<Native>, native: Native| {
+            let integer = Some(integer);
+            run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let main_gate = integer_chip.main_gate();
+                let integer = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+                let limbs = [integer.limb(0), integer.limb(1), integer.limb(2), integer.limb(3)];
+                let composed = &integer_chip.assign_from_limbs(region, limbs, offset)?;
+                let native = main_gate.assign_value(region, &UnassignedValue::new(Some(native)), MainGateColumn::A, offset)?;
+                main_gate.assert_equal(region, composed.native(), native, offset)
+            })
+        };
+
+        let integer = rns.rand_normalized();
+        let limbs = integer.limbs();
+        let shifters = vec![Native::one(), rns.left_shifter_r, rns.left_shifter_2r, rns.left_shifter_3r];
+        let native = limbs.iter().zip(shifters.iter()).fold(Native::zero(), |acc, (limb, shifter)| acc + *limb * shifter);
+
+        assert_eq!(run(integer.clone(), native), Ok(()));
+        assert_ne!(run(integer, native + Native::one()), Ok(()));
+    }
+
+    #[test]
+    fn test_assigned_integer_value() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer = Some(rns.rand_normalized());
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let assigned = integer_chip.assign_integer(region, integer.clone(), offset)?;
+            assert_eq!(assigned.value(), integer.as_ref().map(|integer| integer.value()));
+            Ok(())
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_assign_integer_rejects_wide_limb() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // a malicious prover puts a limb just beyond the limb range
+        let mut limbs = rns.rand_normalized().limbs();
+        limbs[1] = big_to_fe(big_uint::one() << bit_len_limb);
+
+        let integer = Some(rns.new_from_limbs(limbs));
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            integer_chip.assign_integer(region, integer.clone(), offset)?;
+            Ok(())
+        });
+
+        assert_ne!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_sqrt_circuit() {
+        use halo2::arithmetic::Field;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let square = Wrong::rand().square();
+        let non_residue = square * Wrong::ROOT_OF_UNITY;
+
+        for (integer, is_square) in vec![(square, Native::one()), (non_residue, Native::zero()), (Wrong::zero(), Native::one())].into_iter() {
+            let integer = Some(rns.new_in_crt(integer));
+            let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let main_gate = integer_chip.main_gate();
+                let integer = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+                let (root, is_square_0) = integer_chip.sqrt(region, integer, offset)?;
+                let is_square_1 = main_gate.assign_bit(region, Some(is_square), offset)?;
+                main_gate.assert_equal(region, is_square_0, is_square_1, offset)?;
+
+                if is_square == Native::one() {
+                    let root_square = &integer_chip.square(region, &root, offset)?;
+                    integer_chip.assert_equal(region, root_square, integer, offset)?;
+                }
+                Ok(())
+            });
+
+            assert_eq!(result, Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_sqrt_rejects_zero_as_non_square() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // zero times the non residue is still a square of zero so
+        // only the non zero constraint tells the two claims apart
+        let zero = rns.new_from_big(big_uint::from(0u64));
+        for (is_square, expected) in vec![(Native::one(), true), (Native::zero(), false)].into_iter() {
+            let zero = zero.clone();
+            let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let integer = &integer_chip.assign_integer(region, Some(zero.clone()), offset)?;
+                integer_chip.sqrt_with_witness(region, integer, Some((zero.clone(), is_square)), offset)?;
+                Ok(())
+            });
+
+            assert_eq!(result.is_ok(), expected);
+        }
+    }
+
+    #[test]
+    fn test_audit_log() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let (integer_0, integer_1) = (Some(rns.rand_normalized()), Some(rns.rand_normalized()));
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            // log is fresh each time the floor planner runs the region
+            let mut integer_chip = IntegerChip::<Wrong, Native>::new(integer_chip.config.clone(), integer_chip.rns.clone());
+            integer_chip.enable_audit();
+
+            let a = &integer_chip.assign_integer(region, integer_0.clone(), offset)?;
+            let b = &integer_chip.assign_integer(region, integer_1.clone(), offset)?;
+            let c = &integer_chip.mul(region, a, b, offset)?;
+            let c = &integer_chip.add(region, c, a, offset)?;
+            let c = &integer_chip.add(region, c, b, offset)?;
+            let c = &integer_chip.sub(region, c, a, offset)?;
+            let c = &integer_chip.reduce(region, c, offset)?;
+            integer_chip.square(region, c, offset)?;
+
+            let log = integer_chip.audit_log();
+            let operations: Vec<&str> = log.iter().map(|entry| entry.operation).collect();
+            assert_eq!(operations, vec!["mul", "add", "add", "sub", "reduce", "square"]);
+            let max_unreduced_limb = integer_chip.rns.max_unreduced_limb();
+            for entry in log.iter() {
+                assert!(entry.max_vals.iter().all(|max_val| *max_val <= max_unreduced_limb));
             }
+
+            Ok(())
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    fn run_limb_limits_circuit<W: FieldExt, N: FieldExt>(rns: &Rns<W, N>, limbs: Vec<N>, limb_bit_lens: [usize; NUMBER_OF_LIMBS]) -> Result<(), Vec<VerifyFailure>> {
+        let input = Some(rns.new_from_limbs(limbs));
+        run_test_circuit(rns, move |integer_chip, region, offset| {
+            let integer = integer_chip.range_assign_integer_with_limits(region, input.clone().into(), limb_bit_lens, offset)?;
+            let expected = integer_chip.assign_integer(region, input.clone(), offset)?;
+            integer_chip.assert_strict_equal(region, &integer, &expected, offset)
+        })
+    }
+
+    #[test]
+    fn test_range_assign_integer_with_limits() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+
+        let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // middle limbs are known to fit in fewer bits than a full limb
+        let limb_bit_lens = [bit_len_limb, 48, 48, 48];
+        let limbs: Vec<Native> = limb_bit_lens
+            .iter()
+            .map(|bit_len| {
+                use num_bigint::RandBigInt;
+                let limb = rand::thread_rng().gen_biguint(*bit_len as u64);
+                big_to_fe(limb)
+            })
+            .collect();
+        assert_eq!(run_limb_limits_circuit(rns, limbs.clone(), limb_bit_lens), Ok(()));
+
+        // a middle limb beyond its own bound must be rejected
+        let mut limbs = limbs;
+        limbs[1] = big_to_fe(big_uint::one() << 48usize);
+        assert_ne!(run_limb_limits_circuit(rns, limbs, limb_bit_lens), Ok(()));
+    }
+
+    #[test]
+    #[cfg(feature = "no_lookup")]
+    fn test_range_assign_integer_without_lookup() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let limb_bit_lens = [bit_len_limb, 48, 48, 48];
+        let limbs: Vec<Native> = limb_bit_lens
+            .iter()
+            .map(|bit_len| {
+                use num_bigint::RandBigInt;
+                let limb = rand::thread_rng().gen_biguint(*bit_len as u64);
+                big_to_fe(limb)
+            })
+            .collect();
+
+        assert_eq!(run_limb_limits_circuit(rns, limbs.clone(), limb_bit_lens), Ok(()));
+
+        let mut limbs = limbs;
+        limbs[1] = big_to_fe(big_uint::one() << 48usize);
+        assert_ne!(run_limb_limits_circuit(rns, limbs, limb_bit_lens), Ok(()));
+    }
+
+    #[test]
+    fn test_mul_constant_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_a = Some(rns.rand_prenormalized());
+        let constant = big_uint::from(7u64);
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let integer_a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let result_0 = &integer_chip.mul_constant(region, integer_a, constant.clone(), offset)?;
+
+            let constant = Some(integer_chip.rns.new_from_big(constant.clone()));
+            let constant = &integer_chip.assign_integer(region, constant, offset)?;
+            let result_1 = &integer_chip.mul(region, integer_a, constant, offset)?;
+            integer_chip.assert_strict_equal(region, result_0, result_1, offset)?;
+
+            Ok(())
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_assert_not_zero_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // one has zero limbs but is not zero
+        let one = rns.new_from_big(big_uint::one());
+        let wrong_modulus = rns.new_from_limbs(rns.wrong_modulus_decomposed.clone());
+        let zero = rns.new_from_big(big_uint::from(0u64));
+        // p + 1 is one in the field but is not canonical
+        let wrong_modulus_plus_one = rns.new_from_big(rns.wrong_modulus.clone() + 1usize);
+
+        for (input, expected) in vec![
+            (one, true),
+            (rns.rand_normalized(), true),
+            (wrong_modulus, false),
+            (zero, false),
+            (wrong_modulus_plus_one, false),
+        ]
+        .into_iter()
+        {
+            let input = Some(input);
+            let result = run_test_circuit(rns, move |integer_chip, region, offset| {
+                let integer = &integer_chip.assign_integer(region, input.clone(), offset)?;
+                integer_chip.assert_not_zero(region, integer, offset)?;
+
+                Ok(())
+            });
+
+            assert_eq!(result.is_ok(), expected);
         }
+    }
 
-        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
-            let integer_chip = IntegerChip::<W, N>::new(config.integer_config.clone(), self.rns.clone());
+    #[test]
+    fn test_max_vals_tracking() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_0 = Some(rns.rand_normalized());
+        let integer_1 = Some(rns.rand_normalized());
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let a = &integer_chip.assign_integer(region, integer_0.clone(), offset)?;
+            let b = &integer_chip.assign_integer(region, integer_1.clone(), offset)?;
+            let c = &integer_chip.add(region, a, b, offset)?;
+
+            for ((a, b), c) in a.max_vals().iter().zip(b.max_vals().iter()).zip(c.max_vals().iter()) {
+                assert_eq!(*c, a + b);
+            }
+            assert_eq!(c.max_value(), a.max_value() + b.max_value());
+
+            // reduction brings the bound back
+            let d = &integer_chip.reduce(region, c, offset)?;
+            assert!(d.max_value() < c.max_value());
+
+            Ok(())
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_value_with_wide_limbs() {
+        use crate::rns::compose;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 68;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let integer_chip = test_integer_chip(&rns);
+
+        let (a, b) = (rns.rand_normalized(), rns.rand_normalized());
+        integer_chip
+            .count_rows(|integer_chip, region, offset| {
+                let a_assigned = &integer_chip.assign_integer(region, Some(a.clone()), offset)?;
+                let b_assigned = &integer_chip.assign_integer(region, Some(b.clone()), offset)?;
+                let c = &integer_chip.add(region, a_assigned, b_assigned, offset)?;
+
+                assert_eq!(a_assigned.value(), Some(rns.value(&a)));
+                assert_eq!(c.value(), Some(rns.value(&a) + rns.value(&b)));
+                assert_eq!(c.max_value(), compose(c.max_vals().to_vec(), bit_len_limb));
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_lookup"))]
+    fn test_count_rows() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_chip = test_integer_chip(&rns);
+
+        let assign_rows = integer_chip.count_rows(|chip, region, offset| chip.assign_integer(region, None, offset)).unwrap();
+        assert_eq!(assign_rows, 10);
+
+        let reduce_rows = integer_chip
+            .count_rows(|chip, region, offset| {
+                let a = &chip.assign_integer(region, None, offset)?;
+                chip.reduce(region, a, offset)
+            })
+            .unwrap();
+        assert_eq!(reduce_rows - assign_rows, 25);
+
+        let mul_rows = integer_chip
+            .count_rows(|chip, region, offset| {
+                let a = &chip.assign_integer(region, None, offset)?;
+                let b = &chip.assign_integer(region, None, offset)?;
+                chip.mul(region, a, b, offset)
+            })
+            .unwrap();
+        assert_eq!(mul_rows - 2 * assign_rows, 39);
+
+        // symmetric cross terms must not cost more than a generic multiplication
+        let square_rows = integer_chip
+            .count_rows(|chip, region, offset| {
+                let a = &chip.assign_integer(region, None, offset)?;
+                chip.square(region, a, offset)
+            })
+            .unwrap();
+        assert!(square_rows - assign_rows <= mul_rows - 2 * assign_rows);
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_square_and_mul() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        use std::time::Instant;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_chip = test_integer_chip(&rns);
+        let integers: Vec<Integer<Native>> = (0..1000).map(|_| rns.rand_normalized()).collect();
+
+        // layout with witness values, time is dominated by witness generation
+        let run = |square: bool| {
+            let start = Instant::now();
+            let mut rows = 0;
+            for integer in integers.iter() {
+                rows += integer_chip
+                    .count_rows(|chip, region, offset| {
+                        let a = &chip.assign_integer(region, Some(integer.clone()), offset)?;
+                        if square {
+                            chip.square(region, a, offset)
+                        } else {
+                            chip.mul(region, a, a, offset)
+                        }
+                    })
+                    .unwrap();
+            }
+            (start.elapsed(), rows)
+        };
+        let (mul_time, mul_rows) = run(false);
+        let (square_time, square_rows) = run(true);
+
+        println!("mul(a, a): {:?} {} rows, square(a): {:?} {} rows", mul_time, mul_rows, square_time, square_rows);
+        println!("square / mul time ratio: {:.3}", square_time.as_secs_f64() / mul_time.as_secs_f64());
+        assert!(square_rows <= mul_rows);
+        assert!(square_time <= mul_time.mul_f64(1.1));
+    }
+
+    #[test]
+    fn test_reduce_if_gt() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-            layouter.assign_region(
-                || "region 0",
-                |mut region| {
-                    let offset = &mut 0;
-                    let integer_a_0 = &integer_chip.assign_integer(&mut region, self.integer_a.clone(), offset)?.clone();
-                    let integer_c_0 = &integer_chip.assign_integer(&mut region, self.integer_c.clone(), offset)?.clone();
-                    let integer_a_1 = &integer_a_0.clone();
-                    let integer_c_1 = &integer_chip.square(&mut region, integer_a_0, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_c_0, integer_c_1, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_a_0, integer_a_1, offset)?;
-
-                    Ok(())
-                },
-            )?;
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-            let range_chip = RangeChip::<N>::new(config.integer_config.range_config, self.rns.bit_len_lookup);
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_limb_range_table(&mut layouter)?;
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_overflow_range_tables(&mut layouter)?;
+        let integer_0 = Some(rns.rand_normalized());
+        let integer_1 = Some(rns.rand_normalized());
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let a = &integer_chip.assign_integer(region, integer_0.clone(), offset)?;
+            let b = &integer_chip.assign_integer(region, integer_1.clone(), offset)?;
+            let c = &integer_chip.add(region, a, b, offset)?;
+
+            // bound is under threshold so no rows are added
+            let offset_before = *offset;
+            let d = &integer_chip.reduce_if_gt(region, c, c.max_value(), offset)?;
+            assert_eq!(*offset, offset_before);
+            assert_eq!(d.max_value(), c.max_value());
+            integer_chip.assert_strict_equal(region, c, d, offset)?;
+
+            let offset_before = *offset;
+            let d = &integer_chip.reduce_if_gt(region, c, a.max_value(), offset)?;
+            assert!(*offset > offset_before);
+            assert!(d.max_value() < c.max_value());
+            integer_chip.assert_equal(region, c, d, offset)?;
 
             Ok(())
-        }
+        });
+
+        assert_eq!(result, Ok(()));
     }
 
     #[test]
-    fn test_squaring_circuit() {
+    fn test_reduce_on_wide_limb() {
         use halo2::pasta::Fp as Wrong;
         use halo2::pasta::Fq as Native;
 
         let bit_len_limb = 64;
         let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        #[cfg(not(feature = "no_lookup"))]
-        let k: u32 = (rns.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let k: u32 = 8;
+        let integer = Some(rns.new_from_big(big_uint::one()));
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let limb_max_val = integer_chip.rns.max_reduced_limb();
+            let one = big_uint::one();
+            let a = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+            let a = &a.narrow_max([limb_max_val, one.clone(), one.clone(), one]);
 
-        let integer_a = rns.rand_prenormalized();
+            // composed bound is far below max operand while the lowest limb is over a reduced limb
+            let c = &integer_chip.add(region, a, a, offset)?;
+            assert!(c.max_value() <= integer_chip.rns.max_operand());
 
-        let integer_c = rns.mul(&integer_a, &integer_a).result;
+            let offset_before = *offset;
+            let d = &integer_chip.reduce_if_exceeds_max_operand(region, c, offset)?;
+            assert!(*offset > offset_before);
+            integer_chip.assert_equal(region, c, d, offset)?;
 
-        let circuit = TestCircuitSquaring::<Wrong, Native> {
-            integer_a: Some(integer_a),
-            integer_c: Some(integer_c),
-            rns: rns.clone(),
-        };
+            integer_chip.square(region, c, offset)?;
+            integer_chip.mul_constant(region, c, big_uint::from(3u64), offset)?;
 
-        let prover = match MockProver::run(k, &circuit, vec![]) {
-            Ok(prover) => prover,
-            Err(e) => panic!("{:#?}", e),
-        };
+            Ok(())
+        });
 
-        assert_eq!(prover.verify(), Ok(()));
+        assert_eq!(result, Ok(()));
     }
 
-    #[derive(Default, Clone, Debug)]
-    struct TestCircuitInField<W: FieldExt, N: FieldExt> {
-        input: Option<Integer<N>>,
-        rns: Rns<W, N>,
-    }
+    #[test]
+    fn test_narrow_max_skips_reduction() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-    impl<W: FieldExt, N: FieldExt> Circuit<N> for TestCircuitInField<W, N> {
-        type Config = TestCircuitConfig;
-        type FloorPlanner = SimpleFloorPlanner;
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        fn without_witnesses(&self) -> Self {
-            Self::default()
-        }
+        let rand_half_limbs = || {
+            use num_bigint::RandBigInt;
+            let limbs = (0..NUMBER_OF_LIMBS).map(|_| big_to_fe(rand::thread_rng().gen_biguint(bit_len_limb as u64 - 1))).collect();
+            rns.new_from_limbs(limbs)
+        };
 
-        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
-            let main_gate_config = MainGate::<N>::configure(meta);
-            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
-            let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths);
-            let integer_config = IntegerChip::<W, N>::configure(meta, &range_config, &main_gate_config);
-            TestCircuitConfig {
-                integer_config,
-                main_gate_config,
-            }
-        }
+        let integer_0 = Some(rand_half_limbs());
+        let integer_1 = Some(rand_half_limbs());
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let a = &integer_chip.assign_integer(region, integer_0.clone(), offset)?;
+            let b = &integer_chip.assign_integer(region, integer_1.clone(), offset)?;
+            let c = &integer_chip.add(region, a, b, offset)?;
+            let max_operand = integer_chip.rns.max_operand();
+
+            // limbs of the sum are known to fit in a limb
+            let narrowed = &c.narrow_max(a.max_vals());
+            let offset_before = *offset;
+            let d = &integer_chip.reduce_if_gt(region, narrowed, max_operand.clone(), offset)?;
+            assert_eq!(*offset, offset_before);
+            integer_chip.assert_strict_equal(region, c, d, offset)?;
+
+            let offset_before = *offset;
+            integer_chip.reduce_if_gt(region, c, max_operand, offset)?;
+            assert!(*offset > offset_before);
 
-        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
-            let integer_chip = IntegerChip::<W, N>::new(config.integer_config.clone(), self.rns.clone());
+            Ok(())
+        });
 
-            layouter.assign_region(
-                || "region 0",
-                |mut region| {
-                    let offset = &mut 0;
-                    let integer = &integer_chip.assign_integer(&mut region, self.input.clone(), offset)?;
-                    integer_chip.assert_in_field(&mut region, integer, offset)?;
+        assert_eq!(result, Ok(()));
+    }
 
-                    Ok(())
-                },
-            )?;
+    #[test]
+    fn test_mul_reduces_large_operands() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-            let range_chip = RangeChip::<N>::new(config.integer_config.range_config, self.rns.bit_len_lookup);
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_limb_range_table(&mut layouter)?;
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_overflow_range_tables(&mut layouter)?;
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_a = rns.rand_normalized();
+        let integer_b = rns.rand_normalized();
+        let (a, b) = (rns.value(&integer_a), rns.value(&integer_b));
+        let c = ((a + b.clone() * 3usize) * b) % rns.wrong_modulus.clone();
+        let integer_c = rns.new_from_big(c);
+
+        let integer_a = Some(integer_a);
+        let integer_b = Some(integer_b);
+        let integer_c = Some(integer_c);
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let b = &integer_chip.assign_integer(region, integer_b.clone(), offset)?;
+            let c = &integer_chip.assign_integer(region, integer_c.clone(), offset)?;
+
+            // a + 3b exceeds max operand and must be reduced before multiplication
+            let mut t = integer_chip.add(region, a, b, offset)?;
+            t = integer_chip.add(region, &t, b, offset)?;
+            t = integer_chip.add(region, &t, b, offset)?;
+            assert!(t.max_value() > integer_chip.rns.max_operand());
+
+            let d = &integer_chip.mul(region, &t, b, offset)?;
+            assert!(d.max_value() <= integer_chip.rns.max_operand());
+            integer_chip.assert_equal(region, c, d, offset)?;
 
             Ok(())
-        }
+        });
+
+        assert_eq!(result, Ok(()));
     }
 
     #[test]
-    fn test_assert_in_field_circuit() {
+    fn test_emulate_overflows() {
         use halo2::pasta::Fp as Wrong;
         use halo2::pasta::Fq as Native;
+        let bit_len_limb = 68;
 
-        let bit_len_limb = 64;
+        let report = Rns::<Wrong, Native>::emulate_overflows(bit_len_limb);
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+        // residues are range checked with exactly the emulated sizes
+        let integer_chip = test_integer_chip(&rns);
+        assert_eq!(report.mul_v0, integer_chip.mul_v0_range_tune());
+        assert_eq!(report.mul_v1, integer_chip.mul_v1_range_tune());
+        assert_eq!(report.red_v0, integer_chip.red_v0_range_tune());
+        assert_eq!(report.red_v1, integer_chip.red_v1_range_tune());
+
+        assert_eq!(report.max_most_significant_reduced_limb, rns.most_significant_limb_max_val.bits() as usize);
+        assert_eq!(report.max_most_significant_unreduced_limb, rns.max_unreduced_limb().bits() as usize);
+
+        // worst case witnesses stay within the reported sizes
+        let max_operand = rns.new_from_big((big_uint::one() << rns.bit_len_prenormalized) - 1usize);
+        let reduction_context = rns.mul(&max_operand, &max_operand);
+        assert!(fe_to_big(reduction_context.v_0).bits() as usize <= report.mul_v0);
+        assert!(fe_to_big(reduction_context.v_1).bits() as usize <= report.mul_v1);
+
+        let limb: Native = big_to_fe(rns.max_unreduced_limb());
+        let max_unreduced = rns.new_from_limbs(vec![limb; NUMBER_OF_LIMBS]);
+        let reduction_context = rns.reduce(&max_unreduced);
+        assert!(fe_to_big(reduction_context.v_0).bits() as usize <= report.red_v0);
+        assert!(fe_to_big(reduction_context.v_1).bits() as usize <= report.red_v1);
+    }
 
-        #[cfg(not(feature = "no_lookup"))]
-        let k: u32 = (rns.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let k: u32 = 8;
+    #[test]
+    fn test_reduce_large_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-        for i in 0..1 {
-            let integer_in_field = if i == 0 {
-                rns.wrong_modulus_minus_one.clone().into()
-            } else {
-                rns.rand_normalized()
-            };
+        let bit_len_limb = 68;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-            let circuit = TestCircuitInField::<Wrong, Native> {
-                input: Some(integer_in_field),
-                rns: rns.clone(),
-            };
+        // doublings that push limbs far beyond what a single limb quotient reduces
+        let number_of_doublings = 170;
+        let integer_a = rns.rand_normalized();
+        let c = (rns.value(&integer_a) << number_of_doublings) % rns.wrong_modulus.clone();
+        let integer_c = rns.new_from_big(c);
+
+        let integer_a = Some(integer_a);
+        let integer_c = Some(integer_c);
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let c = &integer_chip.assign_integer(region, integer_c.clone(), offset)?;
+
+            let mut t = a.clone();
+            for _ in 0..number_of_doublings {
+                t = integer_chip._add(region, &t, &t, offset)?;
+            }
+            let max_unreduced_limb = integer_chip.rns.max_unreduced_limb();
+            assert!(t.max_vals().iter().all(|max_val| *max_val > max_unreduced_limb));
 
-            let prover = match MockProver::run(k, &circuit, vec![]) {
-                Ok(prover) => prover,
-                Err(e) => panic!("{:#?}", e),
-            };
+            let d = &integer_chip.reduce(region, &t, offset)?;
+            assert!(d.max_value() <= integer_chip.rns.max_operand());
+            integer_chip.assert_strict_equal(region, c, d, offset)?;
 
-            assert_eq!(prover.verify(), Ok(()));
-        }
+            Ok(())
+        });
 
-        let integer_not_in_field = Integer::new(rns.wrong_modulus_decomposed.iter().map(|limb| Limb::<Native>::new(*limb)).collect());
+        assert_eq!(result, Ok(()));
+    }
 
-        let circuit = TestCircuitInField::<Wrong, Native> {
-            input: Some(integer_not_in_field),
-            rns: rns.clone(),
-        };
+    #[test]
+    fn test_reduce_large_rejects_limbs_beyond_native_field() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-        let prover = match MockProver::run(k, &circuit, vec![]) {
-            Ok(prover) => prover,
-            Err(e) => panic!("{:#?}", e),
-        };
+        let bit_len_limb = 68;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let integer_chip = test_integer_chip(&rns);
+
+        // limb bounds grow past the native modulus so chunks of a limb could wrap around
+        let native_bit_len = rns.native_field_modulus().bits() as usize;
+        let integer_a = rns.rand_normalized();
+        let result = integer_chip.count_rows(|integer_chip, region, offset| {
+            let mut t = integer_chip.assign_integer(region, Some(integer_a), offset)?;
+            while t.max_vals().iter().all(|max_val| (max_val.bits() as usize) < native_bit_len) {
+                t = integer_chip._add(region, &t, &t, offset)?;
+            }
+            integer_chip.reduce(region, &t, offset)
+        });
 
-        assert_ne!(prover.verify(), Ok(()));
+        assert!(matches!(result, Err(Error::SynthesisError)));
     }
 
+    #[derive(Clone, Debug)]
+    struct TestCircuitSharedTableConfig {
+        integer_config_0: IntegerConfig,
+        integer_config_1: IntegerConfig,
+    }
 
     #[derive(Default, Clone, Debug)]
-    struct TestCircuitInvert<W: FieldExt, N: FieldExt> {
+    struct TestCircuitSharedTable<W: FieldExt, N: FieldExt> {
         integer_a: Option<Integer<N>>,
         integer_b: Option<Integer<N>>,
-        cond: Option<N>,
+        integer_c: Option<Integer<N>>,
         rns: Rns<W, N>,
     }
 
-    impl<W: FieldExt, N: FieldExt> Circuit<N> for TestCircuitInvert<W, N> {
-        type Config = TestCircuitConfig;
+    impl<W: FieldExt, N: FieldExt> TestCircuitSharedTable<W, N> {
+        fn layout(
+            &self,
+            integer_chip_0: &IntegerChip<W, N>,
+            integer_chip_1: &IntegerChip<W, N>,
+            region: &mut Region<'_, N>,
+            offset: &mut usize,
+        ) -> Result<(), Error> {
+            let a = &integer_chip_0.assign_integer(region, self.integer_a.clone(), offset)?;
+            let b = &integer_chip_0.assign_integer(region, self.integer_b.clone(), offset)?;
+            let c = &integer_chip_0.assign_integer(region, self.integer_c.clone(), offset)?;
+            let c_0 = &integer_chip_0.mul(region, a, b, offset)?;
+            let c_1 = &integer_chip_1.mul(region, a, b, offset)?;
+            integer_chip_0.assert_equal(region, c, c_0, offset)?;
+            integer_chip_1.assert_equal(region, c, c_1, offset)?;
+
+            Ok(())
+        }
+    }
+
+    impl<W: FieldExt, N: FieldExt> Circuit<N> for TestCircuitSharedTable<W, N> {
+        type Config = TestCircuitSharedTableConfig;
         type FloorPlanner = SimpleFloorPlanner;
 
         fn without_witnesses(&self) -> Self {
@@ -720,74 +1869,68 @@ mod tests {
         fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
             let main_gate_config = MainGate::<N>::configure(meta);
             let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
-            let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths);
-            let integer_config = IntegerChip::<W, N>::configure(meta, &range_config, &main_gate_config);
-            TestCircuitConfig {
-                integer_config,
-                main_gate_config,
+            let range_config_0 = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths.clone(), TestCircuitConfig::bit_len_lookup());
+            let range_config_1 = RangeChip::<N>::configure_shared(
+                meta,
+                &main_gate_config,
+                overflow_bit_lengths,
+                TestCircuitConfig::bit_len_lookup(),
+                &range_config_0,
+            );
+            #[cfg(not(feature = "no_lookup"))]
+            assert_eq!(range_config_0.dense_limb_range_table(), range_config_1.dense_limb_range_table());
+
+            let integer_config_0 = IntegerChip::<W, N>::configure(meta, &range_config_0, &main_gate_config);
+            let integer_config_1 = IntegerChip::<W, N>::configure(meta, &range_config_1, &main_gate_config);
+            TestCircuitSharedTableConfig {
+                integer_config_0,
+                integer_config_1,
             }
         }
 
         fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
-            let integer_chip = IntegerChip::<W, N>::new(config.integer_config.clone(), self.rns.clone());
-
-            layouter.assign_region(
-                || "region 0",
-                |mut region| {
-                    let offset = &mut 0;
-                    let integer_a_0 = &integer_chip.assign_integer(&mut region, self.integer_a.clone(), offset)?.clone();
-                    let integer_b_0 = &integer_chip.assign_integer(&mut region, self.integer_b.clone(), offset)?.clone();
-                    let cond_0 = integer_chip.main_gate().assign_bit(&mut region, self.cond.clone(), offset)?.clone();
-                    let integer_a_1 = &integer_a_0.clone();
-                    let (integer_b_1, cond_1) = &integer_chip.invert(&mut region, integer_a_0, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_a_0, integer_a_1, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_b_0, integer_b_1, offset)?;
-                    integer_chip.main_gate().assert_equal(&mut region, cond_0, cond_1.clone(), offset)?;
-
-                    Ok(())
-                },
-            )?;
-
-            let range_chip = RangeChip::<N>::new(config.integer_config.range_config, self.rns.bit_len_lookup);
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_limb_range_table(&mut layouter)?;
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_overflow_range_tables(&mut layouter)?;
+            let integer_chip_0 = IntegerChip::<W, N>::new(config.integer_config_0.clone(), self.rns.clone());
+            let integer_chip_1 = IntegerChip::<W, N>::new(config.integer_config_1.clone(), self.rns.clone());
+
+            layouter.assign_region(|| "region 0", |mut region| self.layout(&integer_chip_0, &integer_chip_1, &mut region, &mut 0))?;
+
+            // chip with the shared config skips tables it doesn't own
+            for integer_config in [config.integer_config_0, config.integer_config_1].iter() {
+                let range_chip = RangeChip::<N>::new(integer_config.range_config.clone(), self.rns.bit_len_lookup);
+                #[cfg(not(feature = "no_lookup"))]
+                range_chip.load_limb_range_table(&mut layouter)?;
+                #[cfg(not(feature = "no_lookup"))]
+                range_chip.load_overflow_range_tables(&mut layouter)?;
+            }
 
             Ok(())
         }
     }
 
     #[test]
-    fn test_invert_circuit() {
+    fn test_shared_range_table() {
         use halo2::pasta::Fp as Wrong;
         use halo2::pasta::Fq as Native;
 
         let bit_len_limb = 64;
         let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        #[cfg(not(feature = "no_lookup"))]
-        let K: u32 = (rns.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let K: u32 = 8;
-
-        let integer_a_cand = rns.rand_prenormalized();
-        let integer_a =
-            if rns.value(&integer_a_cand) % &rns.wrong_modulus == 0u32.into() {
-                rns.new_from_big(1u32.into())
-            } else {
-                integer_a_cand
-            };
-        let integer_b = rns.invert(&integer_a);
+        let integer_a = rns.rand_in_remainder_range();
+        let integer_b = rns.rand_in_remainder_range();
+        let c = (rns.value(&integer_a) * rns.value(&integer_b)) % rns.wrong_modulus.clone();
+        let integer_c = rns.new_from_big(c);
 
-        let circuit = TestCircuitInvert::<Wrong, Native> {
+        let circuit = TestCircuitSharedTable::<Wrong, Native> {
             integer_a: Some(integer_a),
-            integer_b: integer_b,
-            cond: Some(Native::zero()),
+            integer_b: Some(integer_b),
+            integer_c: Some(integer_c),
             rns: rns.clone(),
         };
 
-        let prover = match MockProver::run(K, &circuit, vec![]) {
+        let k = test_k(&test_integer_chip(&rns), rns.bit_len_lookup, |integer_chip, region, offset| {
+            circuit.layout(integer_chip, integer_chip, region, offset)
+        });
+        let prover = match MockProver::run(k, &circuit, vec![]) {
             Ok(prover) => prover,
             Err(e) => panic!("{:#?}", e),
         };
@@ -796,167 +1939,231 @@ mod tests {
     }
 
     #[test]
-    fn test_zero_invert_circuit() {
+    fn test_witness_only_matches_circuit() {
         use halo2::pasta::Fp as Wrong;
         use halo2::pasta::Fq as Native;
 
         let bit_len_limb = 64;
         let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        #[cfg(not(feature = "no_lookup"))]
-        let K: u32 = (rns.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let K: u32 = 8;
+        let integer_a = Some(rns.rand_prenormalized());
+        let integer_b = Some(rns.rand_prenormalized());
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let b = &integer_chip.assign_integer(region, integer_b.clone(), offset)?;
+            let a_plus_b = &integer_chip.add(region, a, b, offset)?;
+            integer_chip.mul_unchecked(region, a, b, offset)?;
+            integer_chip.reduce(region, a_plus_b, offset)?;
+
+            // values of the cells that the two operations above have assigned
+            let assigned_witness = integer_chip.assigned_witness.borrow();
+            let assigned_witness = &assigned_witness[assigned_witness.len() - 2..];
+            if let (Some(integer_a), Some(integer_b)) = (integer_a.as_ref(), integer_b.as_ref()) {
+                let witness_only = integer_chip.witness_only();
+                assert_eq!(witness_only.mul(integer_a, integer_b), assigned_witness[0]);
+                assert_eq!(witness_only.reduce(&a_plus_b.integer().unwrap()), assigned_witness[1]);
+            }
 
-        let integer_a = rns.new_from_big(0u32.into());
-        let integer_b = rns.new_from_big(1u32.into());
+            Ok(())
+        });
 
-        let circuit = TestCircuitInvert::<Wrong, Native> {
-            integer_a: Some(integer_a),
-            integer_b: Some(integer_b),
-            cond: Some(Native::one()),
-            rns: rns.clone(),
-        };
+        assert_eq!(result, Ok(()));
+    }
 
-        let prover = match MockProver::run(K, &circuit, vec![]) {
-            Ok(prover) => prover,
-            Err(e) => panic!("{:#?}", e),
-        };
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "witness sanity check failed at v_0")]
+    fn test_mul_rejects_corrupted_witness() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-        assert_eq!(prover.verify(), Ok(()));
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let mut integer_chip = test_integer_chip(&rns);
+        integer_chip.corrupt_mul_context = Some(|context| context.v_0 = context.v_0 + Native::one());
+
+        let a = rns.rand_prenormalized();
+        let b = rns.rand_prenormalized();
+        integer_chip
+            .count_rows(|integer_chip, region, offset| {
+                let a = &integer_chip.assign_integer(region, Some(a), offset)?;
+                let b = &integer_chip.assign_integer(region, Some(b), offset)?;
+                integer_chip.mul(region, a, b, offset)
+            })
+            .unwrap();
     }
 
+    #[test]
+    fn test_to_bits_and_from_bits_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-    #[derive(Default, Clone, Debug)]
-    struct TestCircuitDivision<W: FieldExt, N: FieldExt> {
-        integer_a: Option<Integer<N>>,
-        integer_b: Option<Integer<N>>,
-        integer_c: Option<Integer<N>>,
-        cond: Option<N>,
-        rns: Rns<W, N>,
+        let hex = "1234567890abcdef0fedcba987654321deadbeefcafebabe0123456789abcdef";
+        let integer = Some(rns.new_from_str(hex));
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let a = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+            let bits = integer_chip.to_bits(region, a, offset)?;
+            assert_eq!(bits.len(), integer_chip.rns.bit_len_limb * NUMBER_OF_LIMBS);
+
+            let b = &integer_chip.from_bits(region, &bits[..], offset)?;
+            integer_chip.assert_strict_equal(region, a, b, offset)?;
+            assert_eq!(b.max_vals(), a.max_vals());
+
+            // bits are little endian so they recompose to the input
+            let bits: Option<Vec<N>> = bits.iter().map(|bit| bit.value()).collect();
+            if let (Some(bits), Some(integer)) = (bits, integer.as_ref()) {
+                let recomposed = bits.iter().rev().fold(big_uint::zero(), |acc, bit| (acc << 1usize) + fe_to_big(*bit));
+                assert_eq!(recomposed, integer.value());
+            }
+
+            Ok(())
+        });
+
+        assert_eq!(result, Ok(()));
     }
 
-    impl<W: FieldExt, N: FieldExt> Circuit<N> for TestCircuitDivision<W, N> {
-        type Config = TestCircuitConfig;
-        type FloorPlanner = SimpleFloorPlanner;
+    #[test]
+    fn test_parity_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
 
-        fn without_witnesses(&self) -> Self {
-            Self::default()
-        }
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
-            let main_gate_config = MainGate::<N>::configure(meta);
-            let overflow_bit_lengths = TestCircuitConfig::overflow_bit_lengths();
-            let range_config = RangeChip::<N>::configure(meta, &main_gate_config, overflow_bit_lengths);
-            let integer_config = IntegerChip::<W, N>::configure(meta, &range_config, &main_gate_config);
-            TestCircuitConfig {
-                integer_config,
-                main_gate_config,
+        let mut integers: Vec<Integer<Native>> = (0..8).map(|_| rns.rand_normalized()).collect();
+        integers.push(rns.new_from_big(big_uint::zero()));
+        integers.push(rns.new_from_big(big_uint::one()));
+        // non canonical witnesses, p is odd so p + x and x have opposite limb parity
+        integers.push(rns.new_from_big(rns.wrong_modulus.clone()));
+        integers.push(rns.new_from_big(rns.wrong_modulus.clone() + 1usize));
+
+        let wrong_modulus = rns.wrong_modulus.clone();
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let main_gate = integer_chip.main_gate();
+            for integer in integers.iter() {
+                let a = &integer_chip.assign_integer(region, Some(integer.clone()), offset)?;
+                let parity = integer_chip.parity(region, a, offset)?;
+
+                let canonical = integer.value() % wrong_modulus.clone();
+                let expected = if canonical.bit(0) { Native::one() } else { Native::zero() };
+                let expected = main_gate.assign_bit(region, Some(expected), offset)?;
+                main_gate.assert_equal(region, parity, expected, offset)?;
             }
-        }
 
-        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<N>) -> Result<(), Error> {
-            let integer_chip = IntegerChip::<W, N>::new(config.integer_config.clone(), self.rns.clone());
+            Ok(())
+        });
 
-            layouter.assign_region(
-                || "region 0",
-                |mut region| {
-                    let offset = &mut 0;
-                    let integer_a_0 = &integer_chip.assign_integer(&mut region, self.integer_a.clone(), offset)?.clone();
-                    let integer_b_0 = &integer_chip.assign_integer(&mut region, self.integer_b.clone(), offset)?.clone();
-                    let integer_c_0 = &integer_chip.assign_integer(&mut region, self.integer_c.clone(), offset)?.clone();
-                    let cond_0 = integer_chip.main_gate().assign_bit(&mut region, self.cond.clone(), offset)?.clone();
-                    let integer_a_1 = &integer_a_0.clone();
-                    let integer_b_1 = &integer_b_0.clone();
-                    let (integer_c_1, cond_1) = &integer_chip.div(&mut region, integer_a_0, integer_b_0, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_a_0, integer_a_1, offset)?;
-                    integer_chip.assert_strict_equal(&mut region, integer_b_0, integer_b_1, offset)?;
-                    integer_chip.assert_equal(&mut region, integer_c_0, integer_c_1, offset)?;
-                    integer_chip.main_gate().assert_equal(&mut region, cond_0, cond_1.clone(), offset)?;
-
-                    Ok(())
-                },
-            )?;
+        assert_eq!(result, Ok(()));
+    }
 
-            let range_chip = RangeChip::<N>::new(config.integer_config.range_config, self.rns.bit_len_lookup);
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_limb_range_table(&mut layouter)?;
-            #[cfg(not(feature = "no_lookup"))]
-            range_chip.load_overflow_range_tables(&mut layouter)?;
+    #[test]
+    fn test_bitwise_circuit() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let bit_len_limb = 64;
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let integer_a = rns.rand_normalized();
+        let integer_b = rns.rand_normalized();
+        let (a, b) = (integer_a.value(), integer_b.value());
+
+        let integer_a = Some(integer_a);
+        let integer_b = Some(integer_b);
+        let integer_xor = Some(rns.new_from_big(a.clone() ^ b.clone()));
+        let integer_and = Some(rns.new_from_big(a & b));
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let b = &integer_chip.assign_integer(region, integer_b.clone(), offset)?;
+            let expected_xor = &integer_chip.assign_integer(region, integer_xor.clone(), offset)?;
+            let expected_and = &integer_chip.assign_integer(region, integer_and.clone(), offset)?;
+
+            let xor = &integer_chip.bitwise_xor(region, a, b, offset)?;
+            let and = &integer_chip.bitwise_and(region, a, b, offset)?;
+            integer_chip.assert_strict_equal(region, xor, expected_xor, offset)?;
+            integer_chip.assert_strict_equal(region, and, expected_and, offset)?;
 
             Ok(())
-        }
+        });
+
+        assert_eq!(result, Ok(()));
     }
 
     #[test]
-    fn test_division_circuit() {
+    fn test_shift_circuit() {
         use halo2::pasta::Fp as Wrong;
         use halo2::pasta::Fq as Native;
 
         let bit_len_limb = 64;
         let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        #[cfg(not(feature = "no_lookup"))]
-        let K: u32 = (rns.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let K: u32 = 8;
+        for shift in vec![0usize, 1, 63, 64, 100, 255].into_iter() {
+            let integer = rns.rand_in_remainder_range();
+            let a = integer.value();
 
-        let integer_a = rns.rand_prenormalized();
-        let integer_b_cand = rns.rand_prenormalized();
-        let integer_b =
-            if rns.value(&integer_b_cand) % &rns.wrong_modulus == 0u32.into() {
-                rns.new_from_big(1u32.into())
-            } else {
-                integer_b_cand
-            };
-        let integer_c = rns.div(&integer_a, &integer_b);
+            let integer = Some(integer);
+            let integer_shl = Some(rns.new_from_big((a.clone() << shift) % rns.wrong_modulus.clone()));
+            let integer_shr = Some(rns.new_from_big(a >> shift));
+            let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+                let a = &integer_chip.assign_integer(region, integer.clone(), offset)?;
+                let expected_shl = &integer_chip.assign_integer(region, integer_shl.clone(), offset)?;
+                let expected_shr = &integer_chip.assign_integer(region, integer_shr.clone(), offset)?;
 
-        let circuit = TestCircuitDivision::<Wrong, Native> {
-            integer_a: Some(integer_a.clone()),
-            integer_b: Some(integer_b),
-            integer_c: integer_c,
-            cond: Some(Native::zero()),
-            rns: rns.clone(),
-        };
+                let shl = &integer_chip.shl_const(region, a, shift, offset)?;
+                let shr = &integer_chip.shr_const(region, a, shift, offset)?;
+                integer_chip.assert_strict_equal(region, shl, expected_shl, offset)?;
+                integer_chip.assert_strict_equal(region, shr, expected_shr, offset)?;
 
-        let prover = match MockProver::run(K, &circuit, vec![]) {
-            Ok(prover) => prover,
-            Err(e) => panic!("{:#?}", e),
-        };
+                let bit_len = integer_chip.rns.bit_len_limb * NUMBER_OF_LIMBS;
+                assert!(integer_chip.shl_const(region, a, bit_len, offset).is_err());
+                assert!(integer_chip.shr_const(region, a, bit_len, offset).is_err());
 
-        assert_eq!(prover.verify(), Ok(()));
+                Ok(())
+            });
+
+            assert_eq!(result, Ok(()));
+        }
     }
 
     #[test]
-    fn test_zero_division_circuit() {
+    fn test_native_circuit() {
         use halo2::pasta::Fp as Wrong;
         use halo2::pasta::Fq as Native;
 
         let bit_len_limb = 64;
         let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
-        #[cfg(not(feature = "no_lookup"))]
-        let K: u32 = (rns.bit_len_lookup + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let K: u32 = 8;
-
-        let integer_a = rns.rand_prenormalized();
-        let integer_b = rns.new_from_big(0u32.into());
-        let integer_c = integer_a.clone();
-
-        let circuit = TestCircuitDivision::<Wrong, Native> {
-            integer_a: Some(integer_a),
-            integer_b: Some(integer_b),
-            integer_c: Some(integer_c),
-            cond: Some(Native::one()),
-            rns: rns.clone(),
-        };
+        let integer_a = Some(rns.rand_in_remainder_range());
+        let integer_b = Some(rns.rand_in_remainder_range());
+        let result = run_test_circuit(&rns, move |integer_chip, region, offset| {
+            let main_gate = integer_chip.main_gate();
+            let rns = &integer_chip.rns;
+            let bases = [Native::one(), rns.left_shifter_r, rns.left_shifter_2r, rns.left_shifter_3r];
+
+            let a = &integer_chip.assign_integer(region, integer_a.clone(), offset)?;
+            let b = &integer_chip.assign_integer(region, integer_b.clone(), offset)?;
+            let c = &integer_chip.mul(region, a, b, offset)?;
+
+            for integer in [a, b, c].iter() {
+                let native = integer_chip.native(region, integer, offset)?;
+                assert_eq!(native.value, integer.integer().map(|integer| integer.native()));
+
+                let terms: Vec<(AssignedValue<Native>, Native)> = (0..NUMBER_OF_LIMBS)
+                    .map(|i| {
+                        let limb = integer.limb(i);
+                        (AssignedValue::new(limb.cell(), limb.value()), bases[i])
+                    })
+                    .collect();
+                let composed = main_gate.compose(region, &terms[..], Native::zero(), offset)?;
+                main_gate.assert_equal(region, native, composed, offset)?;
+            }
 
-        let prover = match MockProver::run(K, &circuit, vec![]) {
-            Ok(prover) => prover,
-            Err(e) => panic!("{:#?}", e),
-        };
+            Ok(())
+        });
 
-        assert_eq!(prover.verify(), Ok(()));
+        assert_eq!(result, Ok(()));
     }
 }
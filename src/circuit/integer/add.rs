@@ -1,10 +1,12 @@
 use super::IntegerChip;
 use crate::circuit::main_gate::MainGateInstructions;
 use crate::circuit::{AssignedInteger, AssignedLimb};
+use crate::rns::Common;
 use crate::NUMBER_OF_LIMBS;
 use halo2::arithmetic::FieldExt;
 use halo2::circuit::Region;
 use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
 
 impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
     pub(crate) fn _add(
@@ -28,6 +30,28 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
         }
         let c_native = main_gate.add(region, a.native(), b.native(), offset)?;
 
-        Ok(AssignedInteger::new(c_limbs, c_native))
+        Ok(AssignedInteger::new(c_limbs, c_native, self.rns.bit_len_limb))
+    }
+
+    pub(crate) fn _add_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: &big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+
+        // Limbs of the constant go to the constant column and grow limb bounds of `a` by their values
+
+        let c = self.rns.new_from_big(c % &self.rns.wrong_modulus);
+        let c_limbs: Vec<N> = c.limbs();
+        let mut result_limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
+
+        for idx in 0..NUMBER_OF_LIMBS {
+            let a_limb = a.limb(idx);
+            let max_val = a_limb.add_fe(c_limbs[idx]);
+            let result_limb = main_gate.add_with_constant(region, a_limb, c_limbs[idx], offset)?;
+
+            result_limbs.push(AssignedLimb::<N>::new(result_limb.cell, result_limb.value, max_val))
+        }
+
+        let result_native = main_gate.add_with_constant(region, a.native(), c.native(), offset)?;
+
+        Ok(AssignedInteger::new(result_limbs, result_native, self.rns.bit_len_limb))
     }
 }
@@ -0,0 +1,41 @@
+use super::{IntegerChip, IntegerInstructions};
+use crate::circuit::main_gate::{CombinationOption, MainGateInstructions, Term};
+use crate::circuit::AssignedInteger;
+use crate::NUMBER_OF_LIMBS;
+use halo2::arithmetic::FieldExt;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+
+impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
+    pub(crate) fn _assert_equal_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: &big_uint, offset: &mut usize) -> Result<(), Error> {
+        let main_gate = self.main_gate();
+        let one = N::one();
+
+        // Reduced limbs of the input are fixed to limbs of the reduced constant
+
+        // | A   | B | C | D |
+        // | --- | - | - | - |
+        // | r_0 | - | - | - |
+        // | r_1 | - | - | - |
+        // | r_2 | - | - | - |
+        // | r_3 | - | - | - |
+
+        let a = &self.reduce(region, a, offset)?;
+        let c = self.rns.new_from_big(c % &self.rns.wrong_modulus);
+        for idx in 0..NUMBER_OF_LIMBS {
+            main_gate.combine(
+                region,
+                Term::Assigned(&a.limb(idx), one),
+                Term::Zero,
+                Term::Zero,
+                Term::Zero,
+                -c.limb_value(idx),
+                offset,
+                CombinationOption::SingleLinerAdd,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -104,6 +104,7 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
         // | c_3 | a_3 | -   | b_2   |
 
         // 0 = -c_3 + p_3 - a_3 - b_2
+        // input larger than p - 1 would leave a negative c_3 which fails its range check
 
         main_gate.combine(
             region,
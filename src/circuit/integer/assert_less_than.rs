@@ -0,0 +1,82 @@
+use super::{IntegerChip, IntegerInstructions};
+use crate::circuit::main_gate::{CombinationOption, MainGateInstructions, Term};
+use crate::circuit::{AssignedInteger, AssignedValue};
+use crate::rns::{Common, Integer};
+use crate::NUMBER_OF_LIMBS;
+use halo2::arithmetic::FieldExt;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+use num_traits::Zero;
+
+impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
+    fn range_tune_assert_less_than_result(&self) -> usize {
+        self.rns.bit_len_limb
+    }
+
+    pub(crate) fn _assert_less_than_constant(
+        &self,
+        region: &mut Region<'_, N>,
+        input: &AssignedInteger<N>,
+        bound: &Integer<N>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        let main_gate = self.main_gate();
+        let (zero, one) = (N::zero(), N::one());
+
+        let bound = bound.value();
+        if bound.is_zero() {
+            // nothing is less than zero so open an unsatisfiable row
+            main_gate.combine(region, Term::Zero, Term::Zero, Term::Zero, Term::Zero, one, offset, CombinationOption::SingleLinerAdd)?;
+            return Ok(());
+        }
+
+        // Input is expected to have limbs in range as in `assert_in_field`
+        // and we show that c = (bound - 1) - a is non negative
+
+        // Constraints:
+        // 0 = -c_0 + m_0 - a_0 + b_0 * R
+        // 0 = -c_1 + m_1 - a_1 + b_1 * R - b_0
+        // 0 = -c_2 + m_2 - a_2 + b_2 * R - b_1
+        // 0 = -c_3 + m_3 - a_3           - b_2
+
+        // Witness layout:
+        // | A   | B   | C   | D     |
+        // | --- | --- | --- | ----- |
+        // | c_0 | a_0 | b_0 | -     |
+        // | c_1 | a_1 | b_1 | b_0   |
+        // | c_2 | a_2 | b_2 | b_1   |
+        // | c_3 | a_3 | -   | b_2   |
+
+        let bound_minus_one = &self.rns.new_from_big(bound - 1usize);
+
+        let comparision_result = input.integer().map(|input| self.rns.compare_to(&input, bound_minus_one));
+
+        let result = comparision_result.as_ref().map(|r| r.result.clone());
+        let result = &self.range_assign_integer(region, result.into(), self.range_tune_assert_less_than_result(), offset)?;
+
+        let mut borrows: Vec<AssignedValue<N>> = Vec::with_capacity(NUMBER_OF_LIMBS - 1);
+        for idx in 0..NUMBER_OF_LIMBS - 1 {
+            let borrow = comparision_result.as_ref().map(|r| if r.borrow[idx] { one } else { zero });
+            borrows.push(main_gate.assign_bit(region, borrow, offset)?.into());
+        }
+
+        let left_shifter = self.rns.left_shifter_r;
+        for idx in 0..NUMBER_OF_LIMBS {
+            let borrow = borrows.get(idx).map_or(Term::Zero, |borrow| Term::Assigned(borrow, left_shifter));
+            let prev_borrow = if idx == 0 { Term::Zero } else { Term::Assigned(&borrows[idx - 1], -one) };
+
+            main_gate.combine(
+                region,
+                Term::Assigned(&result.limb(idx), -one),
+                Term::Assigned(&input.limb(idx), -one),
+                borrow,
+                prev_borrow,
+                bound_minus_one.limb_value(idx),
+                offset,
+                CombinationOption::SingleLinerAdd,
+            )?;
+        }
+
+        Ok(())
+    }
+}
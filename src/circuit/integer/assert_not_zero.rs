@@ -0,0 +1,51 @@
+use super::{IntegerChip, IntegerInstructions};
+use crate::circuit::main_gate::{CombinationOption, MainGateInstructions, Term};
+use crate::circuit::{AssignedInteger, AssignedValue};
+use halo2::arithmetic::FieldExt;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+
+impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
+    pub(crate) fn _assert_not_zero(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
+        let main_gate = self.main_gate();
+        let (zero, one) = (N::zero(), N::one());
+
+        // Input itself must be in [0, p) so that p and any other non canonical
+        // representation is rejected rather than reduced away
+        self.assert_in_field(region, a, offset)?;
+
+        // Limbs are small and non negative so their sum is zero only if all limbs are zero
+        // s = a_0 + a_1 + a_2 + a_3
+
+        // | A   | B   | C   | D     |
+        // | --- | --- | --- | ----- |
+        // | a_0 | a_1 | a_2 | a_3   |
+        // | -   | -   | -   | s     |
+
+        let s = a.integer().map(|a| a.limbs().iter().fold(zero, |s, limb| s + limb));
+
+        main_gate.combine(
+            region,
+            Term::Assigned(&a.limb(0), one),
+            Term::Assigned(&a.limb(1), one),
+            Term::Assigned(&a.limb(2), one),
+            Term::Assigned(&a.limb(3), one),
+            zero,
+            offset,
+            CombinationOption::CombineToNextAdd(-one),
+        )?;
+
+        let (_, _, _, s_cell) = main_gate.combine(
+            region,
+            Term::Zero,
+            Term::Zero,
+            Term::Zero,
+            Term::Unassigned(s, zero),
+            zero,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+
+        main_gate.assert_not_zero(region, AssignedValue::new(s_cell, s), offset)
+    }
+}
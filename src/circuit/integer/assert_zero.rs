@@ -10,17 +10,17 @@ use num_bigint::BigUint as big_uint;
 use num_traits::Zero;
 
 impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
-    fn assert_zero_v0_range_tune(&self) -> usize {
+    pub(crate) fn assert_zero_v0_range_tune(&self) -> usize {
         // TODO
         self.rns.bit_len_limb
     }
 
-    fn assert_zero_v1_range_tune(&self) -> usize {
+    pub(crate) fn assert_zero_v1_range_tune(&self) -> usize {
         // TODO
         self.rns.bit_len_limb
     }
 
-    fn assert_zero_quotient_range_tune(&self) -> usize {
+    pub(crate) fn assert_zero_quotient_range_tune(&self) -> usize {
         // TODO
         self.rns.bit_len_limb
     }
@@ -28,7 +28,7 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
     pub(crate) fn _assert_zero(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(), Error> {
         let main_gate = self.main_gate();
         let (zero, one) = (N::zero(), N::one());
-        let negative_wrong_modulus: Vec<N> = self.rns.negative_wrong_modulus.clone();
+        let negative_wrong_modulus = &self.rns.negative_wrong_modulus;
 
         let reduction_result = a.integer().map(|integer_a| {
             let reduction_result = self.rns.reduce(&integer_a);
@@ -1,9 +1,10 @@
 use super::IntegerChip;
 use crate::circuit::main_gate::{CombinationOption, MainGateInstructions, Term};
 use crate::circuit::range::RangeInstructions;
-use crate::circuit::{AssignedInteger, AssignedLimb, AssignedValue, UnassignedInteger};
+use crate::circuit::{Assigned, AssignedInteger, AssignedLimb, AssignedValue, UnassignedInteger};
 use crate::rns::Common;
-use crate::rns::Integer;
+use crate::rns::{fe_to_big, Integer};
+use crate::NUMBER_OF_LIMBS;
 use halo2::arithmetic::FieldExt;
 use halo2::circuit::Region;
 use halo2::plonk::Error;
@@ -18,22 +19,30 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
         most_significant_limb_bit_len: usize,
         offset: &mut usize,
     ) -> Result<AssignedInteger<N>, Error> {
-        let range_chip = self.range_chip();
-        let max_val = (big_uint::one() << self.rns.bit_len_limb) - 1usize;
-        assert!(most_significant_limb_bit_len <= self.rns.bit_len_limb);
-
-        let assigned = range_chip.range_value(region, &integer.limb(0), self.rns.bit_len_limb, offset)?;
-        let limb_0 = &mut AssignedLimb::new(assigned.cell, assigned.value, max_val.clone());
-
-        let assigned = range_chip.range_value(region, &integer.limb(1), self.rns.bit_len_limb, offset)?;
-        let limb_1 = &mut AssignedLimb::new(assigned.cell, assigned.value, max_val.clone());
-
-        let assigned = range_chip.range_value(region, &integer.limb(2), self.rns.bit_len_limb, offset)?;
-        let limb_2 = &mut AssignedLimb::new(assigned.cell, assigned.value, max_val.clone());
+        let bit_len_limb = self.rns.bit_len_limb;
+        let limb_bit_lens = [bit_len_limb, bit_len_limb, bit_len_limb, most_significant_limb_bit_len];
+        self._range_assign_integer_with_limits(region, integer, limb_bit_lens, offset)
+    }
 
-        let max_val = (big_uint::one() << most_significant_limb_bit_len) - 1usize;
-        let assigned = range_chip.range_value(region, &integer.limb(3), most_significant_limb_bit_len, offset)?;
-        let limb_3 = &mut AssignedLimb::new(assigned.cell, assigned.value, max_val);
+    pub(crate) fn _range_assign_integer_with_limits(
+        &self,
+        region: &mut Region<'_, N>,
+        integer: UnassignedInteger<N>,
+        limb_bit_lens: [usize; NUMBER_OF_LIMBS],
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        let range_chip = self.range_chip();
+        for limb_bit_len in limb_bit_lens.iter() {
+            assert!(*limb_bit_len <= self.rns.bit_len_limb);
+        }
+
+        let mut limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
+        for (idx, limb_bit_len) in limb_bit_lens.iter().enumerate() {
+            let max_val = (big_uint::one() << *limb_bit_len) - 1usize;
+            let assigned = range_chip.range_value(region, &integer.limb(idx), *limb_bit_len, offset)?;
+            limbs.push(AssignedLimb::new(assigned.cell, assigned.value, max_val));
+        }
+        let (limb_0, limb_1, limb_2, limb_3) = (&limbs[0], &limbs[1], &limbs[2], &limbs[3]);
 
         // find the native value
         let main_gate = self.main_gate();
@@ -68,12 +77,113 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
         let native_value = native_value.assign(native_value_cell);
 
         Ok(AssignedInteger {
-            limbs: vec![limb_0.clone(), limb_1.clone(), limb_2.clone(), limb_3.clone()],
+            limbs,
             native_value,
+            bit_len_limb: self.rns.bit_len_limb,
         })
     }
 
-    pub(crate) fn _assign_integer(&self, region: &mut Region<'_, N>, integer: Option<Integer<N>>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+    pub(crate) fn _assign_constant(&self, region: &mut Region<'_, N>, integer: Integer<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+        let one = N::one();
+
+        // Each limb and the native value is fixed by the constant selector
+
+        // | A   | B | C | D |
+        // | --- | - | - | - |
+        // | a_0 | - | - | - |
+        // | a_1 | - | - | - |
+        // | a_2 | - | - | - |
+        // | a_3 | - | - | - |
+        // | n   | - | - | - |
+
+        let mut limbs = Vec::with_capacity(NUMBER_OF_LIMBS);
+        for limb in integer.limbs().into_iter() {
+            let (cell, _, _, _) = main_gate.combine(
+                region,
+                Term::Unassigned(Some(limb), one),
+                Term::Zero,
+                Term::Zero,
+                Term::Zero,
+                -limb,
+                offset,
+                CombinationOption::SingleLinerAdd,
+            )?;
+            limbs.push(AssignedLimb::new(cell, Some(limb), fe_to_big(limb)));
+        }
+
+        let native_value = integer.native();
+        let (cell, _, _, _) = main_gate.combine(
+            region,
+            Term::Unassigned(Some(native_value), one),
+            Term::Zero,
+            Term::Zero,
+            Term::Zero,
+            -native_value,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+        let native_value = AssignedValue::new(cell, Some(native_value));
+
+        Ok(AssignedInteger::new(limbs, native_value, self.rns.bit_len_limb))
+    }
+
+    pub(crate) fn _assign_from_limbs(
+        &self,
+        region: &mut Region<'_, N>,
+        limbs: [AssignedLimb<N>; NUMBER_OF_LIMBS],
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+
+        let (zero, one) = (N::zero(), N::one());
+        let r = self.rns.left_shifter_r;
+        let rr = self.rns.left_shifter_2r;
+        let rrr = self.rns.left_shifter_3r;
+
+        // Limbs are copied and native value is composed at the next row
+
+        // | A   | B   | C   | D   |
+        // | --- | --- | --- | --- |
+        // | a_0 | a_1 | a_2 | a_3 |
+        // | -   | -   | -   | n   |
+
+        main_gate.combine(
+            region,
+            Term::Assigned(&limbs[0], one),
+            Term::Assigned(&limbs[1], r),
+            Term::Assigned(&limbs[2], rr),
+            Term::Assigned(&limbs[3], rrr),
+            zero,
+            offset,
+            CombinationOption::CombineToNextAdd(-one),
+        )?;
+
+        let native_value = limbs
+            .iter()
+            .zip(vec![one, r, rr, rrr].into_iter())
+            .fold(Some(zero), |acc, (limb, base)| match (acc, limb.value()) {
+                (Some(acc), Some(limb)) => Some(acc + limb * base),
+                _ => None,
+            });
+
+        let (_, _, _, native_value_cell) = main_gate.combine(
+            region,
+            Term::Zero,
+            Term::Zero,
+            Term::Zero,
+            Term::Unassigned(native_value, zero),
+            zero,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+
+        let native_value = AssignedValue::new(native_value_cell, native_value);
+
+        Ok(AssignedInteger::new(limbs.to_vec(), native_value, self.rns.bit_len_limb))
+    }
+
+    pub(crate) fn _assign_integer_unsafe(&self, region: &mut Region<'_, N>, integer: Option<Integer<N>>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
         let main_gate = self.main_gate();
 
         let (zero, one) = (N::zero(), N::one());
@@ -121,7 +231,11 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
             value: native_value,
             cell: native_value_cell,
         };
-        let assigned_integer = AssignedInteger { limbs, native_value };
+        let assigned_integer = AssignedInteger {
+            limbs,
+            native_value,
+            bit_len_limb: self.rns.bit_len_limb,
+        };
 
         Ok(assigned_integer)
     }
@@ -0,0 +1,194 @@
+use super::{IntegerChip, IntegerInstructions};
+use crate::circuit::main_gate::{CombinationOption, MainGateInstructions, Term};
+use crate::circuit::range::RangeInstructions;
+use crate::circuit::{Assigned, AssignedBit, AssignedInteger, AssignedLimb, AssignedValue};
+use crate::rns::{big_to_fe, fe_to_big};
+use crate::NUMBER_OF_LIMBS;
+use halo2::arithmetic::FieldExt;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+use num_traits::One;
+
+impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
+    pub(crate) fn _to_bits(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<Vec<AssignedBit<N>>, Error> {
+        let main_gate = self.main_gate();
+        let (zero, one) = (N::zero(), N::one());
+        let bit_len_limb = self.rns.bit_len_limb;
+
+        let two = N::from_u64(2);
+        let bases: Vec<N> = (0..bit_len_limb).map(|i| two.pow(&[i as u64, 0, 0, 0])).collect();
+
+        let mut bits: Vec<AssignedBit<N>> = Vec::with_capacity(bit_len_limb * NUMBER_OF_LIMBS);
+
+        for idx in 0..NUMBER_OF_LIMBS {
+            let limb = &a.limb(idx);
+            let decomposed = limb.decompose(bit_len_limb, 1);
+
+            let mut limb_bits: Vec<AssignedBit<N>> = Vec::with_capacity(bit_len_limb);
+            for i in 0..bit_len_limb {
+                let bit = decomposed.as_ref().map(|decomposed| decomposed[i]);
+                limb_bits.push(main_gate.assign_bit(region, bit, offset)?);
+            }
+
+            // Limb is consumed by three bits at each row and running value
+            // must be zero after the last row
+
+            // | A       | B       | C       | D     |
+            // | ------- | ------- | ------- | ----- |
+            // | b_0     | b_1     | b_2     | limb  |
+            // | b_3     | b_4     | b_5     | t_0   |
+            // | ...     | ...     | ...     | ...   |
+            // | b_(n-1) | -       | -       | t_k   |
+
+            let number_of_rows = (bit_len_limb + 2) / 3;
+            let mut t = limb.value();
+            for (row, chunk) in limb_bits.chunks(3).enumerate() {
+                let term = |j: usize| match chunk.get(j) {
+                    Some(bit) => Term::Assigned(bit, -bases[row * 3 + j]),
+                    None => Term::Zero,
+                };
+
+                let combination_option = if row == number_of_rows - 1 {
+                    CombinationOption::SingleLinerAdd
+                } else {
+                    CombinationOption::CombineToNextAdd(-one)
+                };
+
+                let running = if row == 0 { Term::Assigned(limb, one) } else { Term::Unassigned(t, one) };
+
+                main_gate.combine(region, term(0), term(1), term(2), running, zero, offset, combination_option)?;
+
+                // update running value
+                t = chunk.iter().enumerate().fold(t, |t, (j, bit)| match (t, bit.value()) {
+                    (Some(t), Some(bit)) => Some(t - bit * bases[row * 3 + j]),
+                    _ => None,
+                });
+            }
+
+            bits.extend(limb_bits);
+        }
+
+        Ok(bits)
+    }
+
+    pub(crate) fn _from_bits(&self, region: &mut Region<'_, N>, bits: &[AssignedBit<N>], offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+        let bit_len_limb = self.rns.bit_len_limb;
+
+        // Each limb is packed from at most `bit_len_limb` bits so it is in range given that bits are boolean
+        if bits.len() > bit_len_limb * NUMBER_OF_LIMBS {
+            return Err(Error::SynthesisError);
+        }
+
+        let two = N::from_u64(2);
+        let bases: Vec<N> = (0..bit_len_limb).map(|i| two.pow(&[i as u64, 0, 0, 0])).collect();
+
+        let mut limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
+        let mut chunks = bits.chunks(bit_len_limb);
+        for _ in 0..NUMBER_OF_LIMBS {
+            let chunk = chunks.next().unwrap_or(&[]);
+            let terms: Vec<(AssignedValue<N>, N)> = chunk.iter().zip(bases.iter()).map(|(bit, base)| (bit.clone().into(), *base)).collect();
+            let limb = main_gate.compose(region, &terms[..], N::zero(), offset)?;
+            let max_val = (big_uint::one() << chunk.len()) - 1usize;
+            limbs.push(limb.to_limb(max_val));
+        }
+
+        self._assign_from_limbs(region, [limbs[0].clone(), limbs[1].clone(), limbs[2].clone(), limbs[3].clone()], offset)
+    }
+
+    /// Parity of the field element that `a` represents
+    pub(crate) fn _parity(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedBit<N>, Error> {
+        let main_gate = self.main_gate();
+        let (zero, one) = (N::zero(), N::one());
+
+        // Parity is taken on the canonical form since `a + p` has the opposite parity of `a`
+        let a = &self.reduce(region, a, offset)?;
+        self.assert_in_field(region, a, offset)?;
+        let limb = &a.limb(0);
+
+        let bit = limb.value().map(|limb| if fe_to_big(limb).bit(0) { one } else { zero });
+        let half = limb.value().map(|limb| big_to_fe::<N>(fe_to_big(limb) >> 1usize));
+
+        // Half is ranged in dense limbs wide enough to cover the limb bound
+        let bit_len_lookup = self.rns.bit_len_lookup;
+        let bit_len_half = ((limb.max_val.bits() as usize).max(2) - 1 + bit_len_lookup - 1) / bit_len_lookup * bit_len_lookup;
+
+        let bit = main_gate.assign_bit(region, bit, offset)?;
+        let half = self.range_chip().range_value_with_remainder(region, &half.into(), bit_len_half, offset)?;
+
+        // a_0 = 2 * half + bit
+
+        // | A   | B    | C   | D |
+        // | --- | ---- | --- | - |
+        // | a_0 | half | bit | - |
+
+        main_gate.combine(
+            region,
+            Term::Assigned(limb, -one),
+            Term::Assigned(&half, N::from_u64(2)),
+            Term::Assigned(&bit, one),
+            Term::Zero,
+            zero,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+
+        Ok(bit)
+    }
+
+    /// Shift is taken modulo the wrong field, `a * 2^k` is constrained with a constant multiplication
+    /// so that bits shifted beyond the modulus are reduced rather than dropped
+    pub(crate) fn _shl_const(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, k: usize, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let bit_len = self.rns.bit_len_limb * NUMBER_OF_LIMBS;
+        if k >= bit_len {
+            return Err(Error::SynthesisError);
+        }
+
+        self.mul_constant(region, a, big_uint::one() << k, offset)
+    }
+
+    pub(crate) fn _shr_const(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, k: usize, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let bit_len = self.rns.bit_len_limb * NUMBER_OF_LIMBS;
+        if k >= bit_len {
+            return Err(Error::SynthesisError);
+        }
+
+        let bits = self._to_bits(region, a, offset)?;
+        self._from_bits(region, &bits[k..], offset)
+    }
+
+    pub(crate) fn _bitwise_xor(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        b: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+        let a_bits = self._to_bits(region, a, offset)?;
+        let b_bits = self._to_bits(region, b, offset)?;
+        let mut bits: Vec<AssignedBit<N>> = Vec::with_capacity(a_bits.len());
+        for (a, b) in a_bits.iter().zip(b_bits.iter()) {
+            bits.push(main_gate.xor(region, a, b, offset)?);
+        }
+        self._from_bits(region, &bits[..], offset)
+    }
+
+    pub(crate) fn _bitwise_and(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        b: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+        let a_bits = self._to_bits(region, a, offset)?;
+        let b_bits = self._to_bits(region, b, offset)?;
+        let mut bits: Vec<AssignedBit<N>> = Vec::with_capacity(a_bits.len());
+        for (a, b) in a_bits.iter().zip(b_bits.iter()) {
+            bits.push(main_gate.and(region, a, b, offset)?);
+        }
+        self._from_bits(region, &bits[..], offset)
+    }
+}
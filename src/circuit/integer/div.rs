@@ -1,10 +1,11 @@
 use super::IntegerChip;
 use super::IntegerInstructions;
-use super::AssignedCondition;
 use crate::circuit::AssignedInteger;
 use halo2::arithmetic::FieldExt;
 use halo2::circuit::Region;
 use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+use num_traits::Zero;
 
 impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
     pub(crate) fn _div(
@@ -13,10 +14,21 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
         a: &AssignedInteger<N>,
         b: &AssignedInteger<N>,
         offset: &mut usize,
-    ) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error> {
-        let (b_inv, cond) = self.invert(region, b, offset)?;
-        let a_mul_b_inv = self.mul(region, a, &b_inv, offset)?;
+    ) -> Result<AssignedInteger<N>, Error> {
+        // Quotient is witnessed and checked with a single multiplication
+        // c * b = a
+        // Divisor must be non zero so that c is the only solution
 
-        Ok((a_mul_b_inv, cond))
+        self.assert_not_zero(region, b, offset)?;
+
+        // zero quotient keeps synthesis going for a zero divisor which is rejected above
+        let quotient = match (a.integer(), b.integer()) {
+            (Some(a), Some(b)) => Some(self.rns.div(&a, &b).unwrap_or_else(|| self.rns.new_from_big(big_uint::zero()))),
+            _ => None,
+        };
+        let quotient = self.range_assign_integer(region, quotient.into(), self.mul_result_range_tune(), offset)?;
+        self.mul_assert_equal(region, &quotient, b, a, offset)?;
+
+        Ok(quotient)
     }
 }
@@ -1,13 +1,13 @@
 use super::{AssignedCondition, IntegerChip, IntegerInstructions, MainGateInstructions};
 use crate::circuit::main_gate::{CombinationOption, Term};
-use crate::circuit::{Assigned, AssignedInteger};
+use crate::circuit::{Assigned, AssignedInteger, AssignedValue};
 use crate::NUMBER_OF_LIMBS;
 use halo2::arithmetic::FieldExt;
 use halo2::circuit::Region;
 use halo2::plonk::Error;
 
 impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
-    fn inert_inv_range_tune(&self) -> usize {
+    pub(crate) fn inert_inv_range_tune(&self) -> usize {
         self.rns.bit_len_prenormalized - (self.rns.bit_len_limb * (NUMBER_OF_LIMBS - 1)) + 1
     }
 
@@ -43,17 +43,9 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
             main_gate.assert_zero(region, a_mul_inv.limbs[i].clone(), offset)?;
         }
 
-        // The limbs[0] of a_mul_inv should be 0 or 1, i.e. limbs[0] * limbs[0] - limbs[0] = 0.
-        main_gate.combine(
-            region,
-            Term::Assigned(&a_mul_inv.limbs[0], zero),
-            Term::Assigned(&a_mul_inv.limbs[0], zero),
-            Term::Assigned(&a_mul_inv.limbs[0], -one),
-            Term::Zero,
-            zero,
-            offset,
-            CombinationOption::SingleLinerMul,
-        )?;
+        // The limbs[0] of a_mul_inv should be 0 or 1.
+        let a_mul_inv_0 = &a_mul_inv.limbs[0];
+        main_gate.assert_bit(region, &AssignedValue::new(a_mul_inv_0.cell(), a_mul_inv_0.value()), offset)?;
 
         // If a_mul_inv is 0 (i.e. not 1), then inv must be 1 (i.e. [1, 0, 0, 0]).
         // Here we short x.limbs[i] as x[i].
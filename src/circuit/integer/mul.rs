@@ -1,13 +1,15 @@
 use super::{IntegerChip, IntegerInstructions};
 use crate::circuit::main_gate::{CombinationOption, MainGateInstructions, Term};
 use crate::circuit::range::RangeInstructions;
-use crate::circuit::{AssignedInteger, AssignedValue};
-use crate::rns::Quotient;
+use crate::circuit::{AssignedInteger, AssignedLimb, AssignedValue};
+use crate::rns::{Common, Integer, Quotient, ReductionContext};
 use crate::NUMBER_OF_LIMBS;
 
 use halo2::arithmetic::FieldExt;
 use halo2::circuit::Region;
 use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+use num_traits::One;
 
 impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
     pub(crate) fn mul_v0_range_tune(&self) -> usize {
@@ -28,23 +30,98 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
         self.rns.bit_len_limb
     }
 
+    pub(crate) fn reduce_if_exceeds_max_operand(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        // a small composed bound doesn't keep a single limb from overflowing intermediate values
+        let max_reduced_limb = self.rns.max_reduced_limb();
+        if a.max_vals().iter().any(|max_val| *max_val > max_reduced_limb) {
+            self.reduce(region, a, offset)
+        } else {
+            Ok(a.clone())
+        }
+    }
+
     pub(crate) fn _mul(
         &self,
         region: &mut Region<'_, N>,
         a: &AssignedInteger<N>,
         b: &AssignedInteger<N>,
         offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        let reduction_result = a.integer().map(|integer_a| {
+            let b_integer = b.integer().unwrap();
+            self.witness_only().mul_context(&integer_a, &b_integer)
+        });
+
+        self.mul_with_context(region, a, &Multiplicand::Assigned(b), reduction_result, None, offset)
+    }
+
+    pub(crate) fn _mul_constant(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, c: &big_uint, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let c = &self.rns.new_from_big(c % &self.rns.wrong_modulus);
+        let reduction_result = a.integer().map(|integer_a| self.rns.mul_constant(&integer_a, &c.value()));
+        self.mul_with_context(region, a, &Multiplicand::Constant(c), reduction_result, None, offset)
+    }
+
+    pub(crate) fn _mul_assert_equal(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        b: &AssignedInteger<N>,
+        expected: &AssignedInteger<N>,
+        offset: &mut usize,
+    ) -> Result<(), Error> {
+        // Expected value takes the place of the result in residue rows
+        // so its limbs must be as narrow as limbs of a range checked result
+        let limb_max_val = (big_uint::one() << self.mul_result_range_tune()) - 1usize;
+        if expected.max_vals().iter().any(|max_val| *max_val > limb_max_val) {
+            let result = &self._mul(region, a, b, offset)?;
+            return self.assert_equal(region, result, expected, offset);
+        }
+
+        let reduction_result = match (a.integer(), b.integer(), expected.integer()) {
+            (Some(integer_a), Some(integer_b), Some(integer_expected)) => Some(self.rns.mul_with_result(&integer_a, &integer_b, &integer_expected)),
+            _ => None,
+        };
+
+        self.mul_with_context(region, a, &Multiplicand::Assigned(b), reduction_result, Some(expected), offset)?;
+        Ok(())
+    }
+
+    // Lays out `a * b = q * p + r` for the given witness, `r` is range assigned from the witness unless it is given
+    fn mul_with_context(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        b: &Multiplicand<'_, N>,
+        reduction_result: Option<ReductionContext<N>>,
+        result: Option<&AssignedInteger<N>>,
+        offset: &mut usize,
     ) -> Result<AssignedInteger<N>, Error> {
         let main_gate = self.main_gate();
         let (zero, one) = (N::zero(), N::one());
 
-        let negative_wrong_modulus = self.rns.negative_wrong_modulus.clone();
-
-        let reduction_result = a.integer().map(|integer_a| {
-            let b_integer = b.integer().unwrap();
-            self.rns.mul(&integer_a, &b_integer)
+        #[cfg(test)]
+        let reduction_result = reduction_result.map(|mut reduction_result| {
+            if let Some(corrupt) = self.corrupt_mul_context {
+                corrupt(&mut reduction_result);
+            }
+            reduction_result
         });
 
+        // A wrong expected value of the fused assertion must fail the constraints rather than panic here
+        #[cfg(debug_assertions)]
+        if let (Some(integer_a), Some(integer_b), Some(reduction_result)) = (a.integer(), b.integer(), reduction_result.as_ref()) {
+            if result.is_none() || self.rns.mul_and_check(&integer_a, &integer_b, &reduction_result.result) {
+                self.rns.check_mul_context(&integer_a, &integer_b, reduction_result);
+            }
+        }
+
+        let negative_wrong_modulus = &self.rns.negative_wrong_modulus;
+
         let quotient = reduction_result.as_ref().map(|reduction_result| {
             let quotient = match reduction_result.quotient.clone() {
                 Quotient::Long(quotient) => quotient,
@@ -53,7 +130,7 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
             quotient
         });
 
-        let result = reduction_result.as_ref().map(|u| u.result.clone());
+        let result_witness = reduction_result.as_ref().map(|u| u.result.clone());
         let intermediate_values: Option<Vec<N>> = reduction_result.as_ref().map(|u| u.t.clone());
         let u_0 = reduction_result.as_ref().map(|u| u.u_0);
         let v_0 = reduction_result.as_ref().map(|u| u.v_0);
@@ -64,7 +141,10 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
 
         let range_chip = self.range_chip();
         let quotient = &self.range_assign_integer(region, quotient.into(), self.mul_quotient_range_tune(), offset)?;
-        let result = &self.range_assign_integer(region, result.into(), self.mul_result_range_tune(), offset)?;
+        let result = &match result {
+            Some(result) => result.clone(),
+            None => self.range_assign_integer(region, result_witness.into(), self.mul_result_range_tune(), offset)?,
+        };
         let v_0 = &range_chip.range_value(region, &v_0.into(), self.mul_v0_range_tune(), offset)?;
         let v_1 = &range_chip.range_value(region, &v_1.into(), self.mul_v1_range_tune(), offset)?;
 
@@ -90,7 +170,8 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
         // tmp_b =    a_2 * b_1 + q_2 * p_1 + tmp_c
         // tmp_c =    a_3 * b_0 + q_3 * p_0
 
-        // Witness layout:
+        // Witness layout, limbs of a constant `b` enter as fixed coefficients of `a`
+        // and column B is left empty:
         // | A   | B   | C   | D     |
         // | --- | --- | --- | ----- |
         // | a_0 | b_0 | q_0 | t_0   |
@@ -115,17 +196,13 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
             for j in 0..=i {
                 let k = i - j;
 
-                let combination_option = if k == 0 {
-                    CombinationOption::SingleLinerMul
-                } else {
-                    CombinationOption::CombineToNextMul(one)
-                };
+                let (a_term, b_term, combination_option) = b.product_terms(&a.limbs[j], k);
 
                 let (_, _, _, t_i_cell) = main_gate.combine(
                     region,
-                    Term::Assigned(&a.limb(j), zero),
-                    Term::Assigned(&b.limb(k), zero),
-                    Term::Assigned(&quotient.limb(k), negative_wrong_modulus[j]),
+                    a_term,
+                    b_term,
+                    Term::Assigned(&quotient.limbs[k], negative_wrong_modulus[j]),
                     Term::Unassigned(t, -one),
                     zero,
                     offset,
@@ -159,7 +236,7 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
         let left_shifter_r = self.rns.left_shifter_r;
         let left_shifter_2r = self.rns.left_shifter_2r;
 
-        let (_, _, _, _) = main_gate.combine(
+        main_gate.combine(
             region,
             Term::Assigned(&intermediate_values_cycling[0].clone(), one),
             Term::Assigned(&intermediate_values_cycling[1].clone(), left_shifter_r),
@@ -211,19 +288,79 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
             CombinationOption::SingleLinerAdd,
         )?;
 
+        #[cfg(test)]
+        self.record_witness(&quotient.limbs, result, &intermediate_values_cycling, u_0, u_1, v_0, v_1);
+
         // update native value
 
+        let a_native = &a.native();
+        let (a_term, b_term, combination_option) = b.native_terms(a_native);
         main_gate.combine(
             region,
-            Term::Assigned(&a.native(), zero),
-            Term::Assigned(&b.native(), zero),
+            a_term,
+            b_term,
             Term::Assigned(&quotient.native(), -self.rns.wrong_modulus_in_native_modulus),
             Term::Assigned(&result.native(), -one),
             zero,
             offset,
-            CombinationOption::SingleLinerMul,
+            combination_option,
         )?;
 
         Ok(result.clone())
     }
 }
+
+/// Second operand of a multiplication, limbs of a constant enter the gate as fixed coefficients
+pub(crate) enum Multiplicand<'a, N: FieldExt> {
+    Assigned(&'a AssignedInteger<N>),
+    Constant(&'a Integer<N>),
+}
+
+impl<'a, N: FieldExt> Multiplicand<'a, N> {
+    #[cfg(debug_assertions)]
+    fn integer(&self) -> Option<Integer<N>> {
+        match self {
+            Multiplicand::Assigned(b) => b.integer(),
+            Multiplicand::Constant(c) => Some((*c).clone()),
+        }
+    }
+
+    fn limb_value(&self, idx: usize) -> Result<N, Error> {
+        match self {
+            Multiplicand::Assigned(b) => b.limb_value(idx),
+            Multiplicand::Constant(c) => Ok(c.limb_value(idx)),
+        }
+    }
+
+    // Terms of `a * b` in the native field
+    fn native_terms<'t>(&'t self, a: &'t AssignedValue<N>) -> (Term<'t, N>, Term<'t, N>, CombinationOption<N>) {
+        let zero = N::zero();
+        match self {
+            Multiplicand::Assigned(b) => (Term::Assigned(a, zero), Term::Assigned(&b.native_value, zero), CombinationOption::SingleLinerMul),
+            Multiplicand::Constant(c) => (Term::Assigned(a, c.native()), Term::Zero, CombinationOption::SingleLinerAdd),
+        }
+    }
+
+    // Terms of `a_j * b_k` in a row that adds up into the row below unless `k` is zero
+    fn product_terms<'t>(&'t self, a_j: &'t AssignedLimb<N>, k: usize) -> (Term<'t, N>, Term<'t, N>, CombinationOption<N>) {
+        let (zero, one) = (N::zero(), N::one());
+        match self {
+            Multiplicand::Assigned(b) => {
+                let combination_option = if k == 0 {
+                    CombinationOption::SingleLinerMul
+                } else {
+                    CombinationOption::CombineToNextMul(one)
+                };
+                (Term::Assigned(a_j, zero), Term::Assigned(&b.limbs[k], zero), combination_option)
+            }
+            Multiplicand::Constant(c) => {
+                let combination_option = if k == 0 {
+                    CombinationOption::SingleLinerAdd
+                } else {
+                    CombinationOption::CombineToNextAdd(one)
+                };
+                (Term::Assigned(a_j, c.limb_value(k)), Term::Zero, combination_option)
+            }
+        }
+    }
+}
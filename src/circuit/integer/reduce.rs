@@ -1,35 +1,59 @@
 use super::{IntegerChip, IntegerInstructions};
 use crate::circuit::main_gate::{CombinationOption, MainGateInstructions, Term};
 use crate::circuit::range::RangeInstructions;
-use crate::circuit::{AssignedInteger, AssignedValue};
+use crate::circuit::{Assigned, AssignedInteger, AssignedLimb, AssignedValue};
 use crate::rns::Quotient;
+use crate::NUMBER_OF_LIMBS;
 use halo2::arithmetic::FieldExt;
 use halo2::circuit::Region;
 use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+use num_traits::{One, Zero};
 
 impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
-    fn red_v0_range_tune(&self) -> usize {
+    pub(crate) fn red_v0_range_tune(&self) -> usize {
         self.rns.bit_len_limb
     }
 
-    fn red_v1_range_tune(&self) -> usize {
+    pub(crate) fn red_v1_range_tune(&self) -> usize {
         self.rns.bit_len_limb
     }
 
-    fn red_result_range_tune(&self) -> usize {
+    pub(crate) fn red_result_range_tune(&self) -> usize {
         self.rns.bit_len_limb
     }
 
-    fn red_quotient_range_tune(&self) -> usize {
+    pub(crate) fn red_quotient_range_tune(&self) -> usize {
         self.rns.bit_len_limb
     }
 
+    pub(crate) fn _reduce_if_gt(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        threshold: &big_uint,
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        // bounds are tracked at synthesis time so the layout doesn't depend on witness values
+        if a.max_value() > *threshold {
+            self.reduce(region, a, offset)
+        } else {
+            Ok(a.clone())
+        }
+    }
+
     pub(crate) fn _reduce(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        // quotient of a single reduction must fit in a limb
+        let max_unreduced_limb = self.rns.max_unreduced_limb();
+        if a.max_vals().iter().any(|max_val| *max_val > max_unreduced_limb) {
+            return self._reduce_large(region, a, offset);
+        }
+
         let main_gate = self.main_gate();
         let (zero, one) = (N::zero(), N::one());
-        let negative_wrong_modulus = self.rns.negative_wrong_modulus.clone();
+        let negative_wrong_modulus = &self.rns.negative_wrong_modulus;
 
-        let reduction_result = a.integer().map(|integer_a| self.rns.reduce(&integer_a));
+        let reduction_result = a.integer().map(|integer_a| self.witness_only().reduce_context(&integer_a));
 
         let quotient = reduction_result.as_ref().map(|reduction_result| {
             let quotient = match reduction_result.quotient.clone() {
@@ -176,6 +200,17 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
             CombinationOption::SingleLinerAdd,
         )?;
 
+        #[cfg(test)]
+        self.record_witness(
+            &[quotient.clone()],
+            result,
+            &[t_0.clone(), t_1.clone(), t_2.clone(), t_3.clone()],
+            u_0,
+            u_1,
+            v_0,
+            v_1,
+        );
+
         // update native value
 
         main_gate.combine(
@@ -191,4 +226,80 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
 
         Ok(result.clone())
     }
+
+    // Follows `Rns::reduce_large`. Limbs are decomposed into limb sized chunks, chunks at the same
+    // position are summed and positions are grouped by `NUMBER_OF_LIMBS`. Lowest group is reduced,
+    // higher groups are multiplied by their shifter and the sum of all results is reduced again.
+    fn _reduce_large(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+        let range_chip = self.range_chip();
+        let bit_len_limb = self.rns.bit_len_limb;
+        let chunk_max_val = (big_uint::one() << bit_len_limb) - 1usize;
+
+        let mut positions: Vec<Vec<(AssignedValue<N>, big_uint)>> = vec![];
+        for idx in 0..NUMBER_OF_LIMBS {
+            let limb = a.limb(idx);
+            let bit_len = limb.max_val.bits() as usize;
+            // composition of chunks must not wrap around the native modulus
+            if bit_len >= self.rns.native_field_modulus().bits() as usize {
+                return Err(Error::SynthesisError);
+            }
+            let number_of_chunks = std::cmp::max(1, (bit_len + bit_len_limb - 1) / bit_len_limb);
+            if positions.len() < idx + number_of_chunks {
+                positions.resize(idx + number_of_chunks, vec![]);
+            }
+
+            if number_of_chunks == 1 {
+                positions[idx].push((AssignedValue::new(limb.cell(), limb.value()), limb.max_val.clone()));
+                continue;
+            }
+
+            let chunks = limb.decompose(number_of_chunks, bit_len_limb);
+            let two = N::from_u64(2);
+            let mut terms: Vec<(AssignedValue<N>, N)> = Vec::with_capacity(number_of_chunks);
+            for j in 0..number_of_chunks {
+                let chunk = chunks.as_ref().map(|chunks| chunks[j]);
+                let (chunk, max_val) = if j == number_of_chunks - 1 {
+                    let top_bit_len = bit_len - bit_len_limb * j;
+                    let chunk = range_chip.range_value_with_remainder(region, &chunk.into(), top_bit_len, offset)?;
+                    (chunk, limb.max_val.clone() >> (bit_len_limb * j))
+                } else {
+                    (range_chip.range_value(region, &chunk.into(), bit_len_limb, offset)?, chunk_max_val.clone())
+                };
+                terms.push((chunk.clone(), two.pow(&[(bit_len_limb * j) as u64, 0, 0, 0])));
+                positions[idx + j].push((chunk, max_val));
+            }
+            let composed = main_gate.compose(region, &terms[..], N::zero(), offset)?;
+            main_gate.assert_equal(region, composed, limb, offset)?;
+        }
+
+        let number_of_groups = (positions.len() + NUMBER_OF_LIMBS - 1) / NUMBER_OF_LIMBS;
+        positions.resize(number_of_groups * NUMBER_OF_LIMBS, vec![]);
+
+        let mut results: Vec<AssignedInteger<N>> = Vec::with_capacity(number_of_groups);
+        for (k, group) in positions.chunks(NUMBER_OF_LIMBS).enumerate() {
+            let mut limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
+            for chunks in group.iter() {
+                let terms: Vec<(AssignedValue<N>, N)> = chunks.iter().map(|(chunk, _)| (chunk.clone(), N::one())).collect();
+                let max_val = chunks.iter().fold(big_uint::zero(), |acc, (_, max_val)| acc + max_val);
+                let limb = main_gate.compose(region, &terms[..], N::zero(), offset)?;
+                limbs.push(limb.to_limb(max_val));
+            }
+            let group = self._assign_from_limbs(region, [limbs[0].clone(), limbs[1].clone(), limbs[2].clone(), limbs[3].clone()], offset)?;
+
+            let result = if k == 0 {
+                self._reduce(region, &group, offset)?
+            } else {
+                let shifter = (big_uint::one() << (bit_len_limb * NUMBER_OF_LIMBS * k)) % self.rns.wrong_field_modulus();
+                self.mul_constant(region, &group, shifter, offset)?
+            };
+            results.push(result);
+        }
+
+        let mut sum = results[0].clone();
+        for result in results.iter().skip(1) {
+            sum = self._add(region, &sum, result, offset)?;
+        }
+        self._reduce(region, &sum, offset)
+    }
 }
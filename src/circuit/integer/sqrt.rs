@@ -0,0 +1,59 @@
+use super::{AssignedCondition, IntegerChip, IntegerInstructions, MainGateInstructions};
+use crate::circuit::AssignedInteger;
+use crate::rns::Integer;
+use halo2::arithmetic::FieldExt;
+use halo2::circuit::Region;
+use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
+use num_traits::One;
+
+impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
+    pub(crate) fn _sqrt(&self, region: &mut Region<'_, N>, a: &AssignedInteger<N>, offset: &mut usize) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error> {
+        let (zero, one) = (N::zero(), N::one());
+
+        let non_residue = self.rns.non_residue();
+        let witness = a.integer().map(|a| match self.rns.sqrt(&a) {
+            Some(root) => (root, one),
+            None => {
+                let a_mul_g = self.rns.mul(&a, &non_residue).result;
+                (self.rns.sqrt(&a_mul_g).unwrap(), zero)
+            }
+        });
+
+        self.sqrt_with_witness(region, a, witness, offset)
+    }
+
+    pub(crate) fn sqrt_with_witness(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        witness: Option<(Integer<N>, N)>,
+        offset: &mut usize,
+    ) -> Result<(AssignedInteger<N>, AssignedCondition<N>), Error> {
+        let main_gate = self.main_gate();
+
+        // Either a or a * g is a square where g is a fixed non residue
+        // y^2 = a      if a is a square
+        // y^2 = a * g  otherwise
+        // Zero satisfies both so a must be non zero when it is claimed to be a non square
+
+        let non_residue = self.rns.non_residue();
+        let root = witness.as_ref().map(|(root, _)| root.clone());
+        let root = self.range_assign_integer(region, root.into(), self.rns.bit_len_limb, offset)?;
+        let is_square = main_gate.assign_bit(region, witness.as_ref().map(|(_, is_square)| *is_square), offset)?;
+
+        let non_residue = &self.assign_constant(region, non_residue, offset)?;
+        let a_mul_g = &self.mul(region, a, non_residue, offset)?;
+        let expected = &self.cond_select(region, a, a_mul_g, &is_square, offset)?;
+        let root_square = &self.square(region, &root, offset)?;
+        self.assert_equal(region, root_square, expected, offset)?;
+
+        // a or one when it is a square
+        let one = &self.assign_constant(region, self.rns.new_from_big(big_uint::one()), offset)?;
+        let a = &self.reduce(region, a, offset)?;
+        let a_or_one = &self.cond_select(region, one, a, &is_square, offset)?;
+        self.assert_not_zero(region, a_or_one, offset)?;
+
+        Ok((root, is_square))
+    }
+}
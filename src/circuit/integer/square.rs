@@ -14,9 +14,14 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
         let main_gate = self.main_gate();
         let (zero, one) = (N::zero(), N::one());
 
-        let negative_wrong_modulus = self.rns.negative_wrong_modulus.clone();
+        let negative_wrong_modulus = &self.rns.negative_wrong_modulus;
 
-        let reduction_result = a.integer().map(|integer_a| self.rns.mul(&integer_a, &integer_a));
+        let reduction_result = a.integer().map(|integer_a| self.witness_only().mul_context(&integer_a, &integer_a));
+
+        #[cfg(debug_assertions)]
+        if let (Some(integer_a), Some(reduction_result)) = (a.integer(), reduction_result.as_ref()) {
+            self.rns.check_mul_context(&integer_a, &integer_a, reduction_result);
+        }
 
         let quotient = reduction_result.as_ref().map(|reduction_result| {
             let quotient = match reduction_result.quotient.clone() {
@@ -1,11 +1,12 @@
 use super::IntegerChip;
 use crate::circuit::main_gate::MainGateInstructions;
 use crate::circuit::{AssignedInteger, AssignedLimb};
-use crate::rns::Common;
+use crate::rns::{fe_to_big, Common};
 use crate::NUMBER_OF_LIMBS;
 use halo2::arithmetic::FieldExt;
 use halo2::circuit::Region;
 use halo2::plonk::Error;
+use num_bigint::BigUint as big_uint;
 
 impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
     pub(crate) fn _sub(
@@ -17,8 +18,10 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
     ) -> Result<AssignedInteger<N>, Error> {
         let main_gate = self.main_gate();
 
-        let aux: Vec<N> = self.rns.aux.limbs();
-        let aux_native = self.rns.aux.native();
+        // aux must dominate each limb of `b` so that limbs of the result don't wrap around
+        let aux = self.rns.make_aux(&b.max_vals());
+        let aux_native = aux.native();
+        let aux: Vec<N> = aux.limbs();
         let mut c_limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
 
         for idx in 0..NUMBER_OF_LIMBS {
@@ -33,6 +36,39 @@ impl<W: FieldExt, N: FieldExt> IntegerChip<W, N> {
 
         let c_native = main_gate.sub_with_aux(region, a.native(), b.native(), aux_native, offset)?;
 
-        Ok(AssignedInteger::new(c_limbs, c_native))
+        Ok(AssignedInteger::new(c_limbs, c_native, self.rns.bit_len_limb))
+    }
+
+    pub(crate) fn _sub_constant(
+        &self,
+        region: &mut Region<'_, N>,
+        a: &AssignedInteger<N>,
+        c: &big_uint,
+        offset: &mut usize,
+    ) -> Result<AssignedInteger<N>, Error> {
+        let main_gate = self.main_gate();
+
+        // Limbs of the constant and of the aux are folded into a single constant per limb
+        // and aux is chosen to dominate limbs of the constant
+
+        let c = self.rns.new_from_big(c % &self.rns.wrong_modulus);
+        let c_limbs: Vec<N> = c.limbs();
+        let aux = self
+            .rns
+            .make_aux(&[fe_to_big(c_limbs[0]), fe_to_big(c_limbs[1]), fe_to_big(c_limbs[2]), fe_to_big(c_limbs[3])]);
+        let aux_limbs: Vec<N> = aux.limbs();
+        let mut result_limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
+
+        for idx in 0..NUMBER_OF_LIMBS {
+            let a_limb = a.limb(idx);
+            let max_val = a_limb.add_fe(aux_limbs[idx]) - fe_to_big(c_limbs[idx]);
+            let result_limb = main_gate.sub_with_constant(region, a_limb, c_limbs[idx] - aux_limbs[idx], offset)?;
+
+            result_limbs.push(AssignedLimb::<N>::new(result_limb.cell, result_limb.value, max_val))
+        }
+
+        let result_native = main_gate.sub_with_constant(region, a.native(), c.native() - aux.native(), offset)?;
+
+        Ok(AssignedInteger::new(result_limbs, result_native, self.rns.bit_len_limb))
     }
 }
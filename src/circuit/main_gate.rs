@@ -26,6 +26,7 @@ pub struct MainGateConfig {
     pub sd: Column<Fixed>,
     pub sd_next: Column<Fixed>,
     pub s_mul: Column<Fixed>,
+    pub s_mul_cd: Column<Fixed>,
     pub s_constant: Column<Fixed>,
 }
 
@@ -40,6 +41,9 @@ pub enum CombinationOption<F: FieldExt> {
     SingleLinerAdd,
     CombineToNextMul(F),
     CombineToNextAdd(F),
+    // enables both a * b and c * d products
+    SingleLinerDoubleMul,
+    CombineToNextDoubleMul(F),
 }
 
 pub enum Term<'a, F: FieldExt> {
@@ -107,6 +111,14 @@ pub trait MainGateInstructions<F: FieldExt> {
     ) -> Result<AssignedValue<F>, Error>;
 
     fn assign_bit(&self, region: &mut Region<'_, F>, value: Option<F>, offset: &mut usize) -> Result<AssignedBit<F>, Error>;
+    fn assign_bit_constant(&self, region: &mut Region<'_, F>, value: bool, offset: &mut usize) -> Result<AssignedCondition<F>, Error>;
+    fn assert_bit(&self, region: &mut Region<'_, F>, a: &AssignedValue<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error>;
+
+    fn and(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, b: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error>;
+    fn or(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, b: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error>;
+    fn not(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error>;
+    fn xor(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, b: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error>;
+    fn xnor(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, b: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error>;
 
     fn cond_select(
         &self,
@@ -130,6 +142,7 @@ pub trait MainGateInstructions<F: FieldExt> {
     fn invert(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, offset: &mut usize) -> Result<(AssignedValue<F>, AssignedCondition<F>), Error>;
 
     fn assert_equal(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, b: impl Assigned<F>, offset: &mut usize) -> Result<(), Error>;
+    fn assert_equal_cells(&self, region: &mut Region<'_, F>, a: Cell, b: Cell) -> Result<(), Error>;
     fn assert_not_equal(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, b: impl Assigned<F>, offset: &mut usize) -> Result<(), Error>;
     fn is_equal(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, b: impl Assigned<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error>;
     fn assert_zero(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, offset: &mut usize) -> Result<(), Error>;
@@ -143,8 +156,21 @@ pub trait MainGateInstructions<F: FieldExt> {
     fn sub(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, b: impl Assigned<F>, offset: &mut usize) -> Result<AssignedValue<F>, Error>;
     fn sub_with_aux(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, b: impl Assigned<F>, aux: F, offset: &mut usize)
         -> Result<AssignedValue<F>, Error>;
+    fn add_with_constant(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, constant: F, offset: &mut usize) -> Result<AssignedValue<F>, Error>;
+    fn sub_with_constant(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, constant: F, offset: &mut usize) -> Result<AssignedValue<F>, Error>;
 
     fn mul(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, b: impl Assigned<F>, offset: &mut usize) -> Result<AssignedValue<F>, Error>;
+    fn mul_add(
+        &self,
+        region: &mut Region<'_, F>,
+        a: impl Assigned<F>,
+        b: impl Assigned<F>,
+        c: impl Assigned<F>,
+        d: impl Assigned<F>,
+        offset: &mut usize,
+    ) -> Result<AssignedValue<F>, Error>;
+
+    fn compose(&self, region: &mut Region<'_, F>, terms: &[(AssignedValue<F>, F)], constant: F, offset: &mut usize) -> Result<AssignedValue<F>, Error>;
 
     fn no_operation(&self, region: &mut Region<'_, F>, offset: &mut usize) -> Result<(), Error>;
 
@@ -228,6 +254,42 @@ impl<F: FieldExt> MainGateInstructions<F> for MainGate<F> {
         Ok(AssignedValue::new(cell, c))
     }
 
+    fn add_with_constant(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, constant: F, offset: &mut usize) -> Result<AssignedValue<F>, Error> {
+        let c = a.value().map(|a| a + constant);
+        let one = F::one();
+
+        let (_, _, cell, _) = self.combine(
+            region,
+            Term::Assigned(&a, one),
+            Term::Zero,
+            Term::Unassigned(c, -one),
+            Term::Zero,
+            constant,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+
+        Ok(AssignedValue::new(cell, c))
+    }
+
+    fn sub_with_constant(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, constant: F, offset: &mut usize) -> Result<AssignedValue<F>, Error> {
+        let c = a.value().map(|a| a - constant);
+        let one = F::one();
+
+        let (_, _, cell, _) = self.combine(
+            region,
+            Term::Assigned(&a, one),
+            Term::Zero,
+            Term::Unassigned(c, -one),
+            Term::Zero,
+            -constant,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+
+        Ok(AssignedValue::new(cell, c))
+    }
+
     fn mul(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, b: impl Assigned<F>, offset: &mut usize) -> Result<AssignedValue<F>, Error> {
         let c = match (a.value(), b.value()) {
             (Some(a), Some(b)) => Some(a * b),
@@ -250,6 +312,54 @@ impl<F: FieldExt> MainGateInstructions<F> for MainGate<F> {
         Ok(AssignedValue::new(cell, c))
     }
 
+    fn mul_add(
+        &self,
+        region: &mut Region<'_, F>,
+        a: impl Assigned<F>,
+        b: impl Assigned<F>,
+        c: impl Assigned<F>,
+        d: impl Assigned<F>,
+        offset: &mut usize,
+    ) -> Result<AssignedValue<F>, Error> {
+        let (zero, one) = (F::zero(), F::one());
+
+        // a * b + c * d - out = 0
+
+        // | A | B | C | D   |
+        // | - | - | - | --- |
+        // | a | b | c | d   |
+        // | - | - | - | out |
+
+        let out = match (a.value(), b.value(), c.value(), d.value()) {
+            (Some(a), Some(b), Some(c), Some(d)) => Some(a * b + c * d),
+            _ => None,
+        };
+
+        self.combine(
+            region,
+            Term::assigned_to_mul(&a),
+            Term::assigned_to_mul(&b),
+            Term::assigned_to_mul(&c),
+            Term::assigned_to_mul(&d),
+            zero,
+            offset,
+            CombinationOption::CombineToNextDoubleMul(-one),
+        )?;
+
+        let (_, _, _, cell) = self.combine(
+            region,
+            Term::Zero,
+            Term::Zero,
+            Term::Zero,
+            Term::unassigned_to_mul(out),
+            zero,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+
+        Ok(AssignedValue::new(cell, out))
+    }
+
     fn div_unsafe(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, b: impl Assigned<F>, offset: &mut usize) -> Result<AssignedValue<F>, Error> {
         let c = match (a.value(), b.value()) {
             (Some(a), Some(b)) => match b.invert().into() {
@@ -393,6 +503,11 @@ impl<F: FieldExt> MainGateInstructions<F> for MainGate<F> {
         Ok(())
     }
 
+    fn assert_equal_cells(&self, region: &mut Region<'_, F>, a: Cell, b: Cell) -> Result<(), Error> {
+        // cells may belong to different regions, no rows are opened
+        region.constrain_equal(a, b)
+    }
+
     fn assert_not_equal(&self, region: &mut Region<'_, F>, a: impl Assigned<F>, b: impl Assigned<F>, offset: &mut usize) -> Result<(), Error> {
         // (a - b) must have an inverse
         let c = self.sub_with_aux(region, a, b, F::zero(), offset)?;
@@ -595,6 +710,193 @@ impl<F: FieldExt> MainGateInstructions<F> for MainGate<F> {
         Ok(AssignedBit::<F>::new(cell_2, value))
     }
 
+    fn assign_bit_constant(&self, region: &mut Region<'_, F>, value: bool, offset: &mut usize) -> Result<AssignedCondition<F>, Error> {
+        let one = F::one();
+        let value = if value { one } else { F::zero() };
+
+        // val - constant = 0
+
+        // | A   | B | C | D |
+        // | --- | - | - | - |
+        // | val | - | - | - |
+
+        let (cell, _, _, _) = self.combine(
+            region,
+            Term::Unassigned(Some(value), one),
+            Term::Zero,
+            Term::Zero,
+            Term::Zero,
+            -value,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+
+        Ok(AssignedCondition::new(cell, Some(value)))
+    }
+
+    fn assert_bit(&self, region: &mut Region<'_, F>, a: &AssignedValue<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error> {
+        let (zero, one) = (F::zero(), F::one());
+
+        // a * a - a = 0
+
+        // | A | B | C | D |
+        // | - | - | - | - |
+        // | a | a | a | - |
+
+        self.combine(
+            region,
+            Term::Assigned(a, zero),
+            Term::Assigned(a, zero),
+            Term::Assigned(a, -one),
+            Term::Zero,
+            zero,
+            offset,
+            CombinationOption::SingleLinerMul,
+        )?;
+
+        Ok(AssignedCondition::new(a.cell(), a.value()))
+    }
+
+    fn and(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, b: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error> {
+        let (zero, one) = (F::zero(), F::one());
+
+        // a * b - c = 0
+
+        // | A | B | C | D |
+        // | - | - | - | - |
+        // | a | b | c | - |
+
+        let c = match (a.value(), b.value()) {
+            (Some(a), Some(b)) => Some(a * b),
+            _ => None,
+        };
+
+        let (_, _, c_cell, _) = self.combine(
+            region,
+            Term::Assigned(a, zero),
+            Term::Assigned(b, zero),
+            Term::Unassigned(c, -one),
+            Term::Zero,
+            zero,
+            offset,
+            CombinationOption::SingleLinerMul,
+        )?;
+
+        Ok(AssignedCondition::new(c_cell, c))
+    }
+
+    fn or(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, b: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error> {
+        let (zero, one) = (F::zero(), F::one());
+
+        // a * b - a - b + c = 0
+
+        // | A | B | C | D |
+        // | - | - | - | - |
+        // | a | b | c | - |
+
+        let c = match (a.value(), b.value()) {
+            (Some(a), Some(b)) => Some(a + b - a * b),
+            _ => None,
+        };
+
+        let (_, _, c_cell, _) = self.combine(
+            region,
+            Term::Assigned(a, -one),
+            Term::Assigned(b, -one),
+            Term::Unassigned(c, one),
+            Term::Zero,
+            zero,
+            offset,
+            CombinationOption::SingleLinerMul,
+        )?;
+
+        Ok(AssignedCondition::new(c_cell, c))
+    }
+
+    fn xor(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, b: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error> {
+        let zero = F::zero();
+        let half = F::from_u64(2).invert().unwrap();
+
+        // c = a + b - 2ab is scaled by -1/2 to fit the unit coefficient of the product
+        // a * b - a/2 - b/2 + c/2 = 0
+
+        // | A | B | C | D |
+        // | - | - | - | - |
+        // | a | b | c | - |
+
+        let c = match (a.value(), b.value()) {
+            (Some(a), Some(b)) => Some(a + b - (a * b).double()),
+            _ => None,
+        };
+
+        let (_, _, c_cell, _) = self.combine(
+            region,
+            Term::Assigned(a, -half),
+            Term::Assigned(b, -half),
+            Term::Unassigned(c, half),
+            Term::Zero,
+            zero,
+            offset,
+            CombinationOption::SingleLinerMul,
+        )?;
+
+        Ok(AssignedCondition::new(c_cell, c))
+    }
+
+    fn xnor(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, b: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error> {
+        let half = F::from_u64(2).invert().unwrap();
+
+        // c = 1 - a - b + 2ab is scaled by 1/2 to fit the unit coefficient of the product
+        // a * b - a/2 - b/2 - c/2 + 1/2 = 0
+
+        // | A | B | C | D |
+        // | - | - | - | - |
+        // | a | b | c | - |
+
+        let c = match (a.value(), b.value()) {
+            (Some(a), Some(b)) => Some(F::one() - a - b + (a * b).double()),
+            _ => None,
+        };
+
+        let (_, _, c_cell, _) = self.combine(
+            region,
+            Term::Assigned(a, -half),
+            Term::Assigned(b, -half),
+            Term::Unassigned(c, -half),
+            Term::Zero,
+            half,
+            offset,
+            CombinationOption::SingleLinerMul,
+        )?;
+
+        Ok(AssignedCondition::new(c_cell, c))
+    }
+
+    fn not(&self, region: &mut Region<'_, F>, a: &AssignedCondition<F>, offset: &mut usize) -> Result<AssignedCondition<F>, Error> {
+        let one = F::one();
+
+        // 1 - a - c = 0
+
+        // | A | B | C | D |
+        // | - | - | - | - |
+        // | a | c | - | - |
+
+        let c = a.value().map(|a| one - a);
+
+        let (_, c_cell, _, _) = self.combine(
+            region,
+            Term::Assigned(a, -one),
+            Term::Unassigned(c, -one),
+            Term::Zero,
+            Term::Zero,
+            one,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+
+        Ok(AssignedCondition::new(c_cell, c))
+    }
+
     fn combine(
         &self,
         region: &mut Region<'_, F>,
@@ -626,19 +928,33 @@ impl<F: FieldExt> MainGateInstructions<F> for MainGate<F> {
         match option {
             CombinationOption::CombineToNextMul(base) => {
                 region.assign_fixed(|| "s_mul", self.config.s_mul, *offset, || Ok(F::one()))?;
+                region.assign_fixed(|| "s_mul_cd unused", self.config.s_mul_cd, *offset, || Ok(F::zero()))?;
                 region.assign_fixed(|| "sd_next", self.config.sd_next, *offset, || Ok(base))?;
             }
             CombinationOption::CombineToNextAdd(base) => {
                 region.assign_fixed(|| "sd_next", self.config.sd_next, *offset, || Ok(base))?;
                 region.assign_fixed(|| "s_mul unused", self.config.s_mul, *offset, || Ok(F::zero()))?;
+                region.assign_fixed(|| "s_mul_cd unused", self.config.s_mul_cd, *offset, || Ok(F::zero()))?;
             }
             CombinationOption::SingleLinerMul => {
                 region.assign_fixed(|| "s_mul", self.config.s_mul, *offset, || Ok(F::one()))?;
+                region.assign_fixed(|| "s_mul_cd unused", self.config.s_mul_cd, *offset, || Ok(F::zero()))?;
                 region.assign_fixed(|| "sd_next unused", self.config.sd_next, *offset, || Ok(F::zero()))?;
             }
             CombinationOption::SingleLinerAdd => {
                 region.assign_fixed(|| "sd_next unused", self.config.sd_next, *offset, || Ok(F::zero()))?;
                 region.assign_fixed(|| "s_mul unused", self.config.s_mul, *offset, || Ok(F::zero()))?;
+                region.assign_fixed(|| "s_mul_cd unused", self.config.s_mul_cd, *offset, || Ok(F::zero()))?;
+            }
+            CombinationOption::SingleLinerDoubleMul => {
+                region.assign_fixed(|| "s_mul", self.config.s_mul, *offset, || Ok(F::one()))?;
+                region.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, *offset, || Ok(F::one()))?;
+                region.assign_fixed(|| "sd_next unused", self.config.sd_next, *offset, || Ok(F::zero()))?;
+            }
+            CombinationOption::CombineToNextDoubleMul(base) => {
+                region.assign_fixed(|| "s_mul", self.config.s_mul, *offset, || Ok(F::one()))?;
+                region.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, *offset, || Ok(F::one()))?;
+                region.assign_fixed(|| "sd_next", self.config.sd_next, *offset, || Ok(base))?;
             }
         };
 
@@ -652,6 +968,77 @@ impl<F: FieldExt> MainGateInstructions<F> for MainGate<F> {
         Ok((cell_0, cell_1, cell_2, cell_3))
     }
 
+    fn compose(&self, region: &mut Region<'_, F>, terms: &[(AssignedValue<F>, F)], constant: F, offset: &mut usize) -> Result<AssignedValue<F>, Error> {
+        let (zero, one) = (F::zero(), F::one());
+
+        // Three terms are consumed at each row and running sum is carried to the next row
+
+        // | A     | B     | C     | D     |
+        // | ----- | ----- | ----- | ----- |
+        // | v_0   | v_1   | v_2   | -     |
+        // | v_3   | v_4   | v_5   | r_0   |
+        // | ...   | ...   | ...   | ...   |
+        // | -     | -     | -     | sum   |
+
+        if terms.is_empty() {
+            let sum = Some(constant);
+            let (_, _, _, cell) = self.combine(
+                region,
+                Term::Zero,
+                Term::Zero,
+                Term::Zero,
+                Term::Unassigned(sum, -one),
+                constant,
+                offset,
+                CombinationOption::SingleLinerAdd,
+            )?;
+            return Ok(AssignedValue::new(cell, sum));
+        }
+
+        let mut running = Some(constant);
+        for (row, chunk) in terms.chunks(3).enumerate() {
+            let term = |j: usize| match chunk.get(j) {
+                Some((value, coeff)) => Term::Assigned(value, *coeff),
+                None => Term::Zero,
+            };
+
+            let (running_term, constant_aux) = if row == 0 {
+                (Term::Zero, constant)
+            } else {
+                (Term::Unassigned(running, one), zero)
+            };
+
+            self.combine(
+                region,
+                term(0),
+                term(1),
+                term(2),
+                running_term,
+                constant_aux,
+                offset,
+                CombinationOption::CombineToNextAdd(-one),
+            )?;
+
+            running = chunk.iter().fold(running, |running, (value, coeff)| match (running, value.value()) {
+                (Some(running), Some(value)) => Some(running + value * coeff),
+                _ => None,
+            });
+        }
+
+        let (_, _, _, cell) = self.combine(
+            region,
+            Term::Zero,
+            Term::Zero,
+            Term::Zero,
+            Term::Unassigned(running, zero),
+            zero,
+            offset,
+            CombinationOption::SingleLinerAdd,
+        )?;
+
+        Ok(AssignedValue::new(cell, running))
+    }
+
     fn assign_value(
         &self,
         region: &mut Region<'_, F>,
@@ -674,6 +1061,7 @@ impl<F: FieldExt> MainGateInstructions<F> for MainGate<F> {
 
     fn no_operation(&self, region: &mut Region<'_, F>, offset: &mut usize) -> Result<(), Error> {
         region.assign_fixed(|| "s_mul", self.config.s_mul, *offset, || Ok(F::zero()))?;
+        region.assign_fixed(|| "s_mul_cd", self.config.s_mul_cd, *offset, || Ok(F::zero()))?;
         region.assign_fixed(|| "sc", self.config.sc, *offset, || Ok(F::zero()))?;
         region.assign_fixed(|| "sa", self.config.sa, *offset, || Ok(F::zero()))?;
         region.assign_fixed(|| "sb", self.config.sb, *offset, || Ok(F::zero()))?;
@@ -702,6 +1090,7 @@ impl<F: FieldExt> MainGate<F> {
         let sd = meta.fixed_column();
         let sd_next = meta.fixed_column();
         let s_mul = meta.fixed_column();
+        let s_mul_cd = meta.fixed_column();
         let s_constant = meta.fixed_column();
 
         meta.enable_equality(a.into());
@@ -722,9 +1111,12 @@ impl<F: FieldExt> MainGate<F> {
             let sd = meta.query_fixed(sd, Rotation::cur());
             let sd_next = meta.query_fixed(sd_next, Rotation::cur());
             let s_mul = meta.query_fixed(s_mul, Rotation::cur());
+            let s_mul_cd = meta.query_fixed(s_mul_cd, Rotation::cur());
             let s_constant = meta.query_fixed(s_constant, Rotation::cur());
 
-            vec![a.clone() * sa + b.clone() * sb + a * b * s_mul + c * sc + sd * d + sd_next * d_next + s_constant]
+            vec![
+                a.clone() * sa + b.clone() * sb + a * b * s_mul + c.clone() * sc + d.clone() * sd + c * d * s_mul_cd + sd_next * d_next + s_constant,
+            ]
         });
 
         MainGateConfig {
@@ -739,6 +1131,7 @@ impl<F: FieldExt> MainGate<F> {
             sd_next,
             s_constant,
             s_mul,
+            s_mul_cd,
         }
     }
 }
@@ -1054,11 +1447,12 @@ mod tests {
     }
 
     #[derive(Default, Clone, Debug)]
-    struct TestCircuitBitness<F: FieldExt> {
-        value: Option<F>,
+    struct TestCircuitDoubleMul<F: FieldExt> {
+        coeffs: Option<Vec<F>>,
+        result: F,
     }
 
-    impl<F: FieldExt> Circuit<F> for TestCircuitBitness<F> {
+    impl<F: FieldExt> Circuit<F> for TestCircuitDoubleMul<F> {
         type Config = TestCircuitConfig;
         type FloorPlanner = SimpleFloorPlanner;
 
@@ -1072,19 +1466,44 @@ mod tests {
         }
 
         fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
-            let main_gate = MainGate::<F> {
-                config: config.main_gate_config,
-                _marker: PhantomData,
-            };
+            let main_gate = MainGate::<F>::new(config.main_gate_config);
 
             layouter.assign_region(
                 || "region 0",
                 |mut region| {
-                    let mut offset = 0;
-                    let value = self.value;
-                    let _ = main_gate.assign_bit(&mut region, value, &mut offset)?;
-                    let _ = main_gate.assign_bit(&mut region, value, &mut offset)?;
-                    let _ = main_gate.assign_bit(&mut region, value, &mut offset)?;
+                    let offset = &mut 0;
+                    let coeffs = self.coeffs.clone();
+                    let coeff = |i: usize| coeffs.as_ref().map(|coeffs| coeffs[i]);
+
+                    // a * b + c * d - result = 0
+                    main_gate.combine(
+                        &mut region,
+                        Term::unassigned_to_mul(coeff(0)),
+                        Term::unassigned_to_mul(coeff(1)),
+                        Term::unassigned_to_mul(coeff(2)),
+                        Term::unassigned_to_mul(coeff(3)),
+                        -self.result,
+                        offset,
+                        CombinationOption::SingleLinerDoubleMul,
+                    )?;
+
+                    let a = main_gate.assign_value(&mut region, &coeff(0).into(), super::MainGateColumn::A, offset)?;
+                    let b = main_gate.assign_value(&mut region, &coeff(1).into(), super::MainGateColumn::B, offset)?;
+                    let c = main_gate.assign_value(&mut region, &coeff(2).into(), super::MainGateColumn::C, offset)?;
+                    let d = main_gate.assign_value(&mut region, &coeff(3).into(), super::MainGateColumn::D, offset)?;
+                    let result = main_gate.mul_add(&mut region, a, b, c, d, offset)?;
+
+                    main_gate.combine(
+                        &mut region,
+                        Term::assigned_to_add(&result),
+                        Term::Zero,
+                        Term::Zero,
+                        Term::Zero,
+                        -self.result,
+                        offset,
+                        CombinationOption::SingleLinerAdd,
+                    )?;
+
                     Ok(())
                 },
             )?;
@@ -1094,32 +1513,192 @@ mod tests {
     }
 
     #[test]
-    fn test_main_gate_bitness() {
+    fn test_main_gate_double_mul() {
         const K: u32 = 4;
 
-        let value = Fp::one();
-
-        let circuit = TestCircuitBitness::<Fp> { value: Some(value) };
+        let (a, b, c, d) = (Fp::rand(), Fp::rand(), Fp::rand(), Fp::rand());
+        let result = a * b + c * d;
 
+        let circuit = TestCircuitDoubleMul::<Fp> {
+            coeffs: Some(vec![a, b, c, d]),
+            result,
+        };
         let prover = match MockProver::run(K, &circuit, vec![]) {
             Ok(prover) => prover,
             Err(e) => panic!("{:#?}", e),
         };
-
         assert_eq!(prover.verify(), Ok(()));
 
-        let value = Fp::zero();
-
-        let circuit = TestCircuitBitness::<Fp> { value: Some(value) };
-
+        let circuit = TestCircuitDoubleMul::<Fp> {
+            coeffs: Some(vec![a, b, c, d]),
+            result: result + Fp::one(),
+        };
         let prover = match MockProver::run(K, &circuit, vec![]) {
             Ok(prover) => prover,
             Err(e) => panic!("{:#?}", e),
         };
+        assert_ne!(prover.verify(), Ok(()));
+    }
 
-        assert_eq!(prover.verify(), Ok(()));
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitCompose<F: FieldExt> {
+        values: Option<Vec<F>>,
+        coeffs: Vec<F>,
+        constant: F,
+        result: F,
+    }
 
-        let value = Fp::rand();
+    impl<F: FieldExt> Circuit<F> for TestCircuitCompose<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+            let main_gate = MainGate::<F>::new(config.main_gate_config);
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let offset = &mut 0;
+
+                    let mut terms = vec![];
+                    for (i, coeff) in self.coeffs.iter().enumerate() {
+                        let value = self.values.as_ref().map(|values| values[i]);
+                        let value = main_gate.assign_value(&mut region, &value.into(), super::MainGateColumn::A, offset)?;
+                        terms.push((value, *coeff));
+                    }
+
+                    let sum = main_gate.compose(&mut region, &terms[..], self.constant, offset)?;
+
+                    main_gate.combine(
+                        &mut region,
+                        Term::assigned_to_add(&sum),
+                        Term::Zero,
+                        Term::Zero,
+                        Term::Zero,
+                        -self.result,
+                        offset,
+                        CombinationOption::SingleLinerAdd,
+                    )?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_compose() {
+        const K: u32 = 5;
+
+        let number_of_terms = 9;
+        let values: Vec<Fp> = (0..number_of_terms).map(|_| Fp::rand()).collect();
+        let coeffs: Vec<Fp> = (0..number_of_terms).map(|_| Fp::rand()).collect();
+        let constant = Fp::rand();
+        let result = values.iter().zip(coeffs.iter()).fold(constant, |acc, (value, coeff)| acc + *value * coeff);
+
+        let circuit = TestCircuitCompose::<Fp> {
+            values: Some(values.clone()),
+            coeffs: coeffs.clone(),
+            constant,
+            result,
+        };
+        let prover = match MockProver::run(K, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+
+        let circuit = TestCircuitCompose::<Fp> {
+            values: Some(values),
+            coeffs,
+            constant,
+            result: result + Fp::one(),
+        };
+        let prover = match MockProver::run(K, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_ne!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitBitness<F: FieldExt> {
+        value: Option<F>,
+    }
+
+    impl<F: FieldExt> Circuit<F> for TestCircuitBitness<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+            let main_gate = MainGate::<F> {
+                config: config.main_gate_config,
+                _marker: PhantomData,
+            };
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let mut offset = 0;
+                    let value = self.value;
+                    let _ = main_gate.assign_bit(&mut region, value, &mut offset)?;
+                    let _ = main_gate.assign_bit(&mut region, value, &mut offset)?;
+                    let _ = main_gate.assign_bit(&mut region, value, &mut offset)?;
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_bitness() {
+        const K: u32 = 4;
+
+        let value = Fp::one();
+
+        let circuit = TestCircuitBitness::<Fp> { value: Some(value) };
+
+        let prover = match MockProver::run(K, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+
+        let value = Fp::zero();
+
+        let circuit = TestCircuitBitness::<Fp> { value: Some(value) };
+
+        let prover = match MockProver::run(K, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+
+        let value = Fp::rand();
 
         let circuit = TestCircuitBitness::<Fp> { value: Some(value) };
 
@@ -1131,6 +1710,67 @@ mod tests {
         assert_ne!(prover.verify(), Ok(()));
     }
 
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitAssertBit<F: FieldExt> {
+        value: Option<F>,
+    }
+
+    impl<F: FieldExt> Circuit<F> for TestCircuitAssertBit<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+            let main_gate = MainGate::<F> {
+                config: config.main_gate_config,
+                _marker: PhantomData,
+            };
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let mut offset = 0;
+                    // value is free until assert_bit is applied
+                    let value = main_gate.assign_value(&mut region, &self.value.into(), super::MainGateColumn::A, &mut offset)?;
+                    let _ = main_gate.assert_bit(&mut region, &value, &mut offset)?;
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_assert_bit() {
+        const K: u32 = 4;
+
+        for (value, is_bit) in vec![
+            (Fp::zero(), true),
+            (Fp::one(), true),
+            (Fp::from_u64(2), false),
+            (-Fp::one(), false),
+            (Fp::rand(), false),
+        ] {
+            let circuit = TestCircuitAssertBit::<Fp> { value: Some(value) };
+
+            let prover = match MockProver::run(K, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+
+            assert_eq!(prover.verify().is_ok(), is_bit);
+        }
+    }
+
     #[derive(Default, Clone, Debug)]
     struct TestCircuitEquality<F: FieldExt> {
         a: Option<F>,
@@ -1231,4 +1871,242 @@ mod tests {
 
         assert_eq!(prover.verify(), Ok(()));
     }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitBooleanOps<F: FieldExt> {
+        a: Option<F>,
+        b: Option<F>,
+        and: Option<F>,
+        or: Option<F>,
+        xor: Option<F>,
+        xnor: Option<F>,
+        not_a: Option<F>,
+    }
+
+    impl<F: FieldExt> Circuit<F> for TestCircuitBooleanOps<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+            let main_gate = MainGate::<F> {
+                config: config.main_gate_config,
+                _marker: PhantomData,
+            };
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let mut offset = 0;
+                    let a = &main_gate.assign_bit(&mut region, self.a, &mut offset)?;
+                    let b = &main_gate.assign_bit(&mut region, self.b, &mut offset)?;
+                    let and = &main_gate.and(&mut region, a, b, &mut offset)?;
+                    let or = &main_gate.or(&mut region, a, b, &mut offset)?;
+                    let xor = &main_gate.xor(&mut region, a, b, &mut offset)?;
+                    let xnor = &main_gate.xnor(&mut region, a, b, &mut offset)?;
+                    let not_a = &main_gate.not(&mut region, a, &mut offset)?;
+
+                    let expected = main_gate.assign_value(&mut region, &self.and.into(), super::MainGateColumn::A, &mut offset)?;
+                    main_gate.assert_equal(&mut region, and.clone(), expected, &mut offset)?;
+                    let expected = main_gate.assign_value(&mut region, &self.or.into(), super::MainGateColumn::A, &mut offset)?;
+                    main_gate.assert_equal(&mut region, or.clone(), expected, &mut offset)?;
+                    let expected = main_gate.assign_value(&mut region, &self.xor.into(), super::MainGateColumn::A, &mut offset)?;
+                    main_gate.assert_equal(&mut region, xor.clone(), expected, &mut offset)?;
+                    let expected = main_gate.assign_value(&mut region, &self.xnor.into(), super::MainGateColumn::A, &mut offset)?;
+                    main_gate.assert_equal(&mut region, xnor.clone(), expected, &mut offset)?;
+                    let expected = main_gate.assign_value(&mut region, &self.not_a.into(), super::MainGateColumn::A, &mut offset)?;
+                    main_gate.assert_equal(&mut region, not_a.clone(), expected, &mut offset)?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_boolean_ops() {
+        const K: u32 = 6;
+
+        let bit = |b: bool| if b { Fp::one() } else { Fp::zero() };
+
+        for (a, b) in [(false, false), (false, true), (true, false), (true, true)].iter() {
+            let circuit = TestCircuitBooleanOps::<Fp> {
+                a: Some(bit(*a)),
+                b: Some(bit(*b)),
+                and: Some(bit(*a && *b)),
+                or: Some(bit(*a || *b)),
+                xor: Some(bit(*a ^ *b)),
+                xnor: Some(bit(*a == *b)),
+                not_a: Some(bit(!*a)),
+            };
+
+            let prover = match MockProver::run(K, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+
+            assert_eq!(prover.verify(), Ok(()));
+        }
+
+        // wrong conjunction is rejected
+        let circuit = TestCircuitBooleanOps::<Fp> {
+            a: Some(Fp::one()),
+            b: Some(Fp::zero()),
+            and: Some(Fp::one()),
+            or: Some(Fp::one()),
+            xor: Some(Fp::one()),
+            xnor: Some(Fp::zero()),
+            not_a: Some(Fp::zero()),
+        };
+        let prover = match MockProver::run(K, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_ne!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitBitConstant<F: FieldExt> {
+        a: Option<F>,
+        b: Option<F>,
+    }
+
+    impl<F: FieldExt> Circuit<F> for TestCircuitBitConstant<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+            let main_gate = MainGate::<F> {
+                config: config.main_gate_config,
+                _marker: PhantomData,
+            };
+
+            layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let mut offset = 0;
+                    let a = main_gate.assign_value(&mut region, &self.a.into(), super::MainGateColumn::A, &mut offset)?;
+                    let b = main_gate.assign_value(&mut region, &self.b.into(), super::MainGateColumn::A, &mut offset)?;
+
+                    let cond = &main_gate.assign_bit_constant(&mut region, true, &mut offset)?;
+                    let selected = main_gate.cond_select(&mut region, a.clone(), b.clone(), cond, &mut offset)?;
+                    main_gate.assert_equal(&mut region, selected, a.clone(), &mut offset)?;
+
+                    let cond = &main_gate.assign_bit_constant(&mut region, false, &mut offset)?;
+                    let selected = main_gate.cond_select(&mut region, a, b.clone(), cond, &mut offset)?;
+                    main_gate.assert_equal(&mut region, selected, b, &mut offset)?;
+
+                    Ok(())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_bit_constant() {
+        const K: u32 = 5;
+
+        let circuit = TestCircuitBitConstant::<Fp> {
+            a: Some(Fp::rand()),
+            b: Some(Fp::rand()),
+        };
+
+        let prover = match MockProver::run(K, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitEqualCells<F: FieldExt> {
+        a: Option<F>,
+        b: Option<F>,
+    }
+
+    impl<F: FieldExt> Circuit<F> for TestCircuitEqualCells<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            TestCircuitConfig { main_gate_config }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+            let main_gate = MainGate::<F> {
+                config: config.main_gate_config,
+                _marker: PhantomData,
+            };
+
+            let a = layouter.assign_region(
+                || "region 0",
+                |mut region| {
+                    let mut offset = 0;
+                    main_gate.assign_value(&mut region, &self.a.into(), super::MainGateColumn::A, &mut offset)
+                },
+            )?;
+
+            layouter.assign_region(
+                || "region 1",
+                |mut region| {
+                    let mut offset = 0;
+                    let b = main_gate.assign_value(&mut region, &self.b.into(), super::MainGateColumn::B, &mut offset)?;
+                    main_gate.assert_equal_cells(&mut region, a.cell(), b.cell())
+                },
+            )?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_main_gate_equal_cells() {
+        const K: u32 = 4;
+
+        let a = Fp::rand();
+
+        let circuit = TestCircuitEqualCells::<Fp> { a: Some(a), b: Some(a) };
+        let prover = match MockProver::run(K, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+
+        let circuit = TestCircuitEqualCells::<Fp> {
+            a: Some(a),
+            b: Some(a + Fp::one()),
+        };
+        let prover = match MockProver::run(K, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_ne!(prover.verify(), Ok(()));
+    }
 }
@@ -1,11 +1,21 @@
 use super::UnassignedValue;
-use crate::circuit::main_gate::{CombinationOption, MainGate, MainGateColumn, MainGateConfig, MainGateInstructions, Term};
+#[cfg(not(feature = "no_lookup"))]
+use crate::circuit::main_gate::{CombinationOption, MainGateColumn, Term};
+use crate::circuit::main_gate::{MainGate, MainGateConfig, MainGateInstructions};
 use crate::circuit::AssignedValue;
+use crate::rns::{big_to_fe, fe_to_big};
 use crate::NUMBER_OF_LOOKUP_LIMBS;
 use halo2::arithmetic::FieldExt;
-use halo2::circuit::{Chip, Layouter, Region};
-use halo2::plonk::{ConstraintSystem, Error, Selector, TableColumn};
+#[cfg(not(feature = "no_lookup"))]
+use halo2::circuit::Layouter;
+use halo2::circuit::{Chip, Region};
+use halo2::plonk::{ConstraintSystem, Error};
+#[cfg(not(feature = "no_lookup"))]
+use halo2::plonk::{Selector, TableColumn};
+#[cfg(not(feature = "no_lookup"))]
 use halo2::poly::Rotation;
+use num_bigint::BigUint as big_uint;
+use num_traits::One;
 
 #[cfg(not(feature = "no_lookup"))]
 #[derive(Clone, Debug)]
@@ -13,11 +23,13 @@ pub struct TableConfig {
     selector: Selector,
     column: TableColumn,
     bit_len: usize,
+    shared: bool,
 }
 
 #[derive(Clone, Debug)]
 pub struct RangeConfig {
     main_gate_config: MainGateConfig,
+    bit_len_lookup: usize,
 
     #[cfg(not(feature = "no_lookup"))]
     s_dense_limb_range: Selector,
@@ -25,10 +37,21 @@ pub struct RangeConfig {
     #[cfg(not(feature = "no_lookup"))]
     dense_limb_range_table: TableColumn,
 
+    #[cfg(not(feature = "no_lookup"))]
+    dense_limb_range_table_shared: bool,
+
     #[cfg(not(feature = "no_lookup"))]
     fine_tune_tables: Vec<TableConfig>,
 }
 
+impl RangeConfig {
+    /// Table column of the dense limb range lookup
+    #[cfg(not(feature = "no_lookup"))]
+    pub fn dense_limb_range_table(&self) -> TableColumn {
+        self.dense_limb_range_table
+    }
+}
+
 pub struct RangeChip<F: FieldExt> {
     config: RangeConfig,
     base_bit_len: usize,
@@ -69,6 +92,13 @@ impl<F: FieldExt> Chip<F> for RangeChip<F> {
 
 pub trait RangeInstructions<F: FieldExt>: Chip<F> {
     fn range_value(&self, region: &mut Region<'_, F>, input: &UnassignedValue<F>, bit_len: usize, offset: &mut usize) -> Result<AssignedValue<F>, Error>;
+    fn range_value_with_remainder(
+        &self,
+        region: &mut Region<'_, F>,
+        input: &UnassignedValue<F>,
+        bit_len: usize,
+        offset: &mut usize,
+    ) -> Result<AssignedValue<F>, Error>;
 
     #[cfg(not(feature = "no_lookup"))]
     fn load_limb_range_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error>;
@@ -77,6 +107,7 @@ pub trait RangeInstructions<F: FieldExt>: Chip<F> {
 }
 
 impl<F: FieldExt> RangeInstructions<F> for RangeChip<F> {
+    #[cfg(not(feature = "no_lookup"))]
     fn range_value(&self, region: &mut Region<'_, F>, input: &UnassignedValue<F>, bit_len: usize, offset: &mut usize) -> Result<AssignedValue<F>, Error> {
         let main_gate = self.main_gate();
         let (one, zero) = (F::one(), F::zero());
@@ -185,15 +216,17 @@ impl<F: FieldExt> RangeInstructions<F> for RangeChip<F> {
             if has_overflow {
                 self.get_table(fine_limb_bit_len)?.selector.enable(region, *offset)?;
                 // make first combination witness values
-                let coeffs = limbs.as_ref().map(|limbs| {
-                    // last limb is the overflow value
-                    let overflow_value = limbs[number_of_limbs - 1];
-                    // input value must exist if limbs do
-                    let input_value = input.value().unwrap();
-                    // combination of previous row must go to column 'D'
-                    let intermediate_combination = input_value - overflow_value * rrrr;
-                    (overflow_value, input_value, intermediate_combination)
-                });
+                // witnesses are absent at key generation so cells are assigned with no values
+                let coeffs = match (limbs.as_ref(), input.value) {
+                    (Some(limbs), Some(input_value)) => {
+                        // last limb is the overflow value
+                        let overflow_value = limbs[number_of_limbs - 1];
+                        // combination of previous row must go to column 'D'
+                        let intermediate_combination = input_value - overflow_value * rrrr;
+                        Some((overflow_value, input_value, intermediate_combination))
+                    }
+                    _ => None,
+                };
 
                 // Second row must constain
                 // a_4 * R^4 - input + t  = 0
@@ -217,8 +250,92 @@ impl<F: FieldExt> RangeInstructions<F> for RangeChip<F> {
         assigned
     }
 
+    #[cfg(feature = "no_lookup")]
+    fn range_value(&self, region: &mut Region<'_, F>, input: &UnassignedValue<F>, bit_len: usize, offset: &mut usize) -> Result<AssignedValue<F>, Error> {
+        let main_gate = self.main_gate();
+        assert!(bit_len > 0);
+
+        // Without lookup tables input is decomposed into bits where each bit
+        // is constrained to be boolean and composed back to the input
+
+        let bits = input.decompose(bit_len, 1);
+        let two = F::from_u64(2);
+        let mut terms: Vec<(AssignedValue<F>, F)> = Vec::with_capacity(bit_len);
+        for i in 0..bit_len {
+            let bit = bits.as_ref().map(|bits| bits[i]);
+            let bit = main_gate.assign_bit(region, bit, offset)?;
+            terms.push((bit.into(), two.pow(&[i as u64, 0, 0, 0])));
+        }
+
+        main_gate.compose(region, &terms[..], F::zero(), offset)
+    }
+
+    fn range_value_with_remainder(
+        &self,
+        region: &mut Region<'_, F>,
+        input: &UnassignedValue<F>,
+        bit_len: usize,
+        offset: &mut usize,
+    ) -> Result<AssignedValue<F>, Error> {
+        let main_gate = self.main_gate();
+
+        // Input is split into dense limbs and a remainder of `bit_len % base_bit_len` bits. Dense
+        // limbs are ranged against the limb table with up to `NUMBER_OF_LOOKUP_LIMBS` limbs in a
+        // row. Remainder is ranged in a row of its own so that it is looked up only in its overflow
+        // table and never in the limb table column
+        let number_of_dense_limbs = bit_len / self.base_bit_len;
+        let remainder_bit_len = bit_len % self.base_bit_len;
+        let dense_bit_len = number_of_dense_limbs * self.base_bit_len;
+        let piece_bit_len = self.base_bit_len * NUMBER_OF_LOOKUP_LIMBS;
+
+        // (bit length, shift) of each piece
+        let mut pieces: Vec<(usize, usize)> = (0..dense_bit_len)
+            .step_by(piece_bit_len)
+            .map(|shift| (std::cmp::min(piece_bit_len, dense_bit_len - shift), shift))
+            .collect();
+        if remainder_bit_len != 0 {
+            pieces.push((remainder_bit_len, dense_bit_len));
+        }
+        if pieces.len() == 1 {
+            return self.range_value(region, input, bit_len, offset);
+        }
+        let number_of_pieces = pieces.len();
+
+        // Last piece is not masked so that an out of range input fails at its range check
+        let values: Option<Vec<F>> = input.value.map(|input| {
+            let input = fe_to_big(input);
+            pieces
+                .iter()
+                .enumerate()
+                .map(|(i, (bit_len, shift))| {
+                    let piece = input.clone() >> *shift;
+                    let piece = if i == number_of_pieces - 1 {
+                        piece
+                    } else {
+                        piece & ((big_uint::one() << *bit_len) - 1usize)
+                    };
+                    big_to_fe(piece)
+                })
+                .collect()
+        });
+
+        let two = F::from_u64(2);
+        let mut terms: Vec<(AssignedValue<F>, F)> = Vec::with_capacity(number_of_pieces);
+        for (i, (bit_len, shift)) in pieces.iter().enumerate() {
+            let piece = values.as_ref().map(|values| values[i]);
+            let assigned = self.range_value(region, &piece.into(), *bit_len, offset)?;
+            terms.push((assigned, two.pow(&[*shift as u64, 0, 0, 0])));
+        }
+
+        main_gate.compose(region, &terms[..], F::zero(), offset)
+    }
+
     #[cfg(not(feature = "no_lookup"))]
     fn load_limb_range_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        // shared table is loaded by the chip that owns it
+        if self.config.dense_limb_range_table_shared {
+            return Ok(());
+        }
         let table_values: Vec<F> = (0..1 << self.base_bit_len).map(|e| F::from_u64(e)).collect();
 
         layouter.assign_table(
@@ -235,7 +352,7 @@ impl<F: FieldExt> RangeInstructions<F> for RangeChip<F> {
 
     #[cfg(not(feature = "no_lookup"))]
     fn load_overflow_range_tables(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
-        for overflow_table in self.config.fine_tune_tables.iter() {
+        for overflow_table in self.config.fine_tune_tables.iter().filter(|table| !table.shared) {
             let bit_len = overflow_table.bit_len;
             let table_values: Vec<F> = (0..1 << bit_len).map(|e| F::from_u64(e)).collect();
 
@@ -256,6 +373,7 @@ impl<F: FieldExt> RangeInstructions<F> for RangeChip<F> {
 
 impl<F: FieldExt> RangeChip<F> {
     pub fn new(config: RangeConfig, base_bit_len: usize) -> Self {
+        assert_eq!(config.bit_len_lookup, base_bit_len, "range chip must be constructed with the configured lookup width");
         let two = F::from_u64(2);
         let left_shifter_r = two.pow(&[base_bit_len as u64, 0, 0, 0]);
         let left_shifter_2r = two.pow(&[(base_bit_len * 2) as u64, 0, 0, 0]);
@@ -269,16 +387,63 @@ impl<F: FieldExt> RangeChip<F> {
         }
     }
 
-    pub fn configure(meta: &mut ConstraintSystem<F>, main_gate_config: &MainGateConfig, fine_tune_bit_lengths: Vec<usize>) -> RangeConfig {
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        main_gate_config: &MainGateConfig,
+        fine_tune_bit_lengths: Vec<usize>,
+        bit_len_lookup: usize,
+    ) -> RangeConfig {
+        Self::configure_with_tables(meta, main_gate_config, fine_tune_bit_lengths, bit_len_lookup, None)
+    }
+
+    /// Configures range lookups reusing tables of `shared` with matching bit lengths rather than allocating them again.
+    /// Only the chip owning the tables should load them.
+    pub fn configure_shared(
+        meta: &mut ConstraintSystem<F>,
+        main_gate_config: &MainGateConfig,
+        fine_tune_bit_lengths: Vec<usize>,
+        bit_len_lookup: usize,
+        shared: &RangeConfig,
+    ) -> RangeConfig {
+        Self::configure_with_tables(meta, main_gate_config, fine_tune_bit_lengths, bit_len_lookup, Some(shared))
+    }
+
+    fn configure_with_tables(
+        meta: &mut ConstraintSystem<F>,
+        main_gate_config: &MainGateConfig,
+        fine_tune_bit_lengths: Vec<usize>,
+        bit_len_lookup: usize,
+        shared: Option<&RangeConfig>,
+    ) -> RangeConfig {
+        // Overflow limbs are what remains after dense limbs so they must be shorter than the dense table
+        assert!(bit_len_lookup > 0);
+        for bit_len in fine_tune_bit_lengths.iter() {
+            assert!(
+                *bit_len > 0 && *bit_len < bit_len_lookup,
+                "overflow length {} cannot be covered with {} bit lookup table",
+                bit_len,
+                bit_len_lookup
+            );
+        }
+
+        #[cfg(not(feature = "no_lookup"))]
         let a = main_gate_config.a;
+        #[cfg(not(feature = "no_lookup"))]
         let b = main_gate_config.b;
+        #[cfg(not(feature = "no_lookup"))]
         let c = main_gate_config.c;
+        #[cfg(not(feature = "no_lookup"))]
         let d = main_gate_config.d;
 
         #[cfg(not(feature = "no_lookup"))]
         let s_dense_limb_range = meta.complex_selector();
         #[cfg(not(feature = "no_lookup"))]
-        let dense_limb_range_table = meta.lookup_table_column();
+        let (dense_limb_range_table, dense_limb_range_table_shared) = match shared {
+            Some(shared) if shared.bit_len_lookup == bit_len_lookup => (shared.dense_limb_range_table, true),
+            _ => (meta.lookup_table_column(), false),
+        };
+        #[cfg(feature = "no_lookup")]
+        let _ = shared;
 
         #[cfg(not(feature = "no_lookup"))]
         {
@@ -312,7 +477,11 @@ impl<F: FieldExt> RangeChip<F> {
             .iter()
             .map(|bit_len| {
                 let selector = meta.complex_selector();
-                let column = meta.lookup_table_column();
+                let shared_column = shared.and_then(|shared| shared.fine_tune_tables.iter().find(|table| table.bit_len == *bit_len).map(|table| table.column));
+                let (column, shared) = match shared_column {
+                    Some(column) => (column, true),
+                    None => (meta.lookup_table_column(), false),
+                };
 
                 meta.lookup(|meta| {
                     let exp = meta.query_advice(b.into(), Rotation::cur());
@@ -324,6 +493,7 @@ impl<F: FieldExt> RangeChip<F> {
                     selector,
                     column,
                     bit_len: *bit_len,
+                    shared,
                 };
                 table_config
             })
@@ -331,11 +501,14 @@ impl<F: FieldExt> RangeChip<F> {
 
         RangeConfig {
             main_gate_config: main_gate_config.clone(),
+            bit_len_lookup,
             #[cfg(not(feature = "no_lookup"))]
             s_dense_limb_range,
             #[cfg(not(feature = "no_lookup"))]
             dense_limb_range_table,
             #[cfg(not(feature = "no_lookup"))]
+            dense_limb_range_table_shared,
+            #[cfg(not(feature = "no_lookup"))]
             fine_tune_tables,
         }
     }
@@ -346,10 +519,11 @@ mod tests {
 
     use super::{RangeChip, RangeConfig, RangeInstructions};
     use crate::circuit::main_gate::{MainGate, MainGateConfig};
+    use crate::circuit::tests::test_k;
     use crate::circuit::UnassignedValue;
     use crate::NUMBER_OF_LOOKUP_LIMBS;
     use halo2::arithmetic::FieldExt;
-    use halo2::circuit::{Layouter, SimpleFloorPlanner};
+    use halo2::circuit::{Layouter, Region, SimpleFloorPlanner};
     use halo2::dev::MockProver;
     use halo2::pasta::Fp;
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
@@ -360,6 +534,14 @@ mod tests {
         range_config: RangeConfig,
     }
 
+    // Range chip in the test configuration to lay out operations outside of a circuit
+    fn test_range_chip<F: FieldExt>(base_bit_len: usize) -> RangeChip<F> {
+        let meta = &mut ConstraintSystem::<F>::default();
+        let main_gate_config = MainGate::<F>::configure(meta);
+        let range_config = RangeChip::<F>::configure(meta, &main_gate_config, (1..base_bit_len).collect(), base_bit_len);
+        RangeChip::<F>::new(range_config, base_bit_len)
+    }
+
     #[derive(Default, Clone, Debug)]
     struct TestCircuit<F: FieldExt> {
         input: Vec<(usize, Option<F>)>,
@@ -373,6 +555,16 @@ mod tests {
         fn base_bit_len() -> usize {
             16
         }
+
+        fn layout(&self, range_chip: &RangeChip<F>, region: &mut Region<'_, F>, offset: &mut usize) -> Result<(), Error> {
+            for value in self.input.iter() {
+                let bit_len = value.0;
+                let value = value.1;
+                range_chip.range_value(region, &UnassignedValue::new(value), bit_len, offset)?;
+            }
+
+            Ok(())
+        }
     }
 
     impl<F: FieldExt> Circuit<F> for TestCircuit<F> {
@@ -386,7 +578,7 @@ mod tests {
         fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
             let main_gate_config = MainGate::<F>::configure(meta);
             let fine_tune_bit_lengths = Self::fine_tune_bit_lengths();
-            let range_config = RangeChip::<F>::configure(meta, &main_gate_config, fine_tune_bit_lengths);
+            let range_config = RangeChip::<F>::configure(meta, &main_gate_config, fine_tune_bit_lengths, Self::base_bit_len());
             TestCircuitConfig {
                 main_gate_config,
                 range_config,
@@ -396,20 +588,136 @@ mod tests {
         fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
             let range_chip = RangeChip::<F>::new(config.range_config.clone(), Self::base_bit_len());
 
-            layouter.assign_region(
-                || "region 0",
-                |mut region| {
-                    let mut offset = 0;
+            layouter.assign_region(|| "region 0", |mut region| self.layout(&range_chip, &mut region, &mut 0))?;
 
-                    for value in self.input.iter() {
-                        let bit_len = value.0;
-                        let value = value.1;
-                        range_chip.range_value(&mut region, &UnassignedValue::new(value), bit_len, &mut offset)?;
-                    }
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
 
-                    Ok(())
-                },
-            )?;
+            Ok(())
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitRemainder<F: FieldExt> {
+        input: Vec<(usize, Option<F>)>,
+    }
+
+    impl<F: FieldExt> TestCircuitRemainder<F> {
+        fn layout(&self, range_chip: &RangeChip<F>, region: &mut Region<'_, F>, offset: &mut usize) -> Result<(), Error> {
+            for value in self.input.iter() {
+                let bit_len = value.0;
+                let value = value.1;
+                range_chip.range_value_with_remainder(region, &UnassignedValue::new(value), bit_len, offset)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<F: FieldExt> Circuit<F> for TestCircuitRemainder<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            let fine_tune_bit_lengths = TestCircuit::<F>::fine_tune_bit_lengths();
+            let range_config = RangeChip::<F>::configure(meta, &main_gate_config, fine_tune_bit_lengths, TestCircuit::<F>::base_bit_len());
+            TestCircuitConfig {
+                main_gate_config,
+                range_config,
+            }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+            let range_chip = RangeChip::<F>::new(config.range_config.clone(), TestCircuit::<F>::base_bit_len());
+
+            layouter.assign_region(|| "region 0", |mut region| self.layout(&range_chip, &mut region, &mut 0))?;
+
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_limb_range_table(&mut layouter)?;
+            #[cfg(not(feature = "no_lookup"))]
+            range_chip.load_overflow_range_tables(&mut layouter)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_range_with_remainder_circuit() {
+        let base_bit_len = TestCircuit::<Fp>::base_bit_len();
+
+        // with and without a remainder, and with dense limbs over more than a row
+        for bit_len in vec![17, 20, 70, 96, 100] {
+            let input = vec![(bit_len, Some(Fp::from_u128((1 << bit_len) - 1)))];
+            let circuit = TestCircuitRemainder::<Fp> { input };
+            let k = test_k(&test_range_chip(base_bit_len), base_bit_len, |range_chip, region, offset| {
+                circuit.layout(range_chip, region, offset)
+            });
+            let prover = match MockProver::run(k, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+            assert_eq!(prover.verify(), Ok(()));
+
+            let input = vec![(bit_len, Some(Fp::from_u128(1 << bit_len)))];
+            let circuit = TestCircuitRemainder::<Fp> { input };
+            let prover = match MockProver::run(k, &circuit, vec![]) {
+                Ok(prover) => prover,
+                Err(e) => panic!("{:#?}", e),
+            };
+            assert_ne!(prover.verify(), Ok(()));
+        }
+    }
+
+    #[derive(Default, Clone, Debug)]
+    struct TestCircuitWideTable<F: FieldExt> {
+        input: Vec<(usize, Option<F>)>,
+    }
+
+    impl<F: FieldExt> TestCircuitWideTable<F> {
+        fn base_bit_len() -> usize {
+            12
+        }
+
+        fn layout(&self, range_chip: &RangeChip<F>, region: &mut Region<'_, F>, offset: &mut usize) -> Result<(), Error> {
+            for value in self.input.iter() {
+                let bit_len = value.0;
+                let value = value.1;
+                range_chip.range_value(region, &UnassignedValue::new(value), bit_len, offset)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl<F: FieldExt> Circuit<F> for TestCircuitWideTable<F> {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            let main_gate_config = MainGate::<F>::configure(meta);
+            let fine_tune_bit_lengths = (1..Self::base_bit_len()).collect();
+            let range_config = RangeChip::<F>::configure(meta, &main_gate_config, fine_tune_bit_lengths, Self::base_bit_len());
+            TestCircuitConfig {
+                main_gate_config,
+                range_config,
+            }
+        }
+
+        fn synthesize(&self, config: Self::Config, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+            let range_chip = RangeChip::<F>::new(config.range_config.clone(), Self::base_bit_len());
+
+            layouter.assign_region(|| "region 0", |mut region| self.layout(&range_chip, &mut region, &mut 0))?;
 
             #[cfg(not(feature = "no_lookup"))]
             range_chip.load_limb_range_table(&mut layouter)?;
@@ -420,13 +728,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_range_circuit_wide_table() {
+        let base_bit_len = TestCircuitWideTable::<Fp>::base_bit_len();
+
+        let max_bit_len = base_bit_len * (NUMBER_OF_LOOKUP_LIMBS + 1) - 1;
+        let input = (1..(max_bit_len + 1)).map(|i| (i, Some(Fp::from_u128((1 << i) - 1)))).collect();
+
+        let circuit = TestCircuitWideTable::<Fp> { input };
+        let k = test_k(&test_range_chip(base_bit_len), base_bit_len, |range_chip, region, offset| {
+            circuit.layout(range_chip, region, offset)
+        });
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_eq!(prover.verify(), Ok(()));
+
+        let input = vec![(max_bit_len, Some(Fp::from_u128(1 << max_bit_len)))];
+        let circuit = TestCircuitWideTable::<Fp> { input };
+        let prover = match MockProver::run(k, &circuit, vec![]) {
+            Ok(prover) => prover,
+            Err(e) => panic!("{:#?}", e),
+        };
+        assert_ne!(prover.verify(), Ok(()));
+    }
+
     #[test]
     fn test_range_circuit() {
         let base_bit_len = TestCircuit::<Fp>::base_bit_len();
-        #[cfg(not(feature = "no_lookup"))]
-        let k: u32 = (base_bit_len + 1) as u32;
-        #[cfg(feature = "no_lookup")]
-        let k: u32 = 8;
 
         let min_bit_len = 1;
         let max_bit_len = base_bit_len * (NUMBER_OF_LOOKUP_LIMBS + 1) - 1;
@@ -440,6 +770,10 @@ mod tests {
             .collect();
 
         let circuit = TestCircuit::<Fp> { input };
+        // negative cases range a single value so they fit the same size
+        let k = test_k(&test_range_chip(base_bit_len), base_bit_len, |range_chip, region, offset| {
+            circuit.layout(range_chip, region, offset)
+        });
 
         let prover = match MockProver::run(k, &circuit, vec![]) {
             Ok(prover) => prover,
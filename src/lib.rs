@@ -1,5 +1,5 @@
 mod circuit;
-mod rns;
+pub mod rns;
 
 pub(crate) const BIT_LEN_LIMB: usize = 64;
 pub(crate) const NUMBER_OF_LIMBS: usize = 4;
@@ -2,16 +2,18 @@ use crate::{BIT_LEN_LIMB, NUMBER_OF_LIMBS, NUMBER_OF_LOOKUP_LIMBS};
 use halo2::arithmetic::FieldExt;
 use num_bigint::BigUint as big_uint;
 use num_integer::Integer as _;
-use num_traits::{Num, One, Zero};
+use num_traits::{CheckedSub, Num, One, ToPrimitive, Zero};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use std::fmt;
 use std::marker::PhantomData;
 use std::ops::{Div, Shl};
 
-pub fn decompose_fe<F: FieldExt>(e: F, number_of_limbs: usize, bit_len: usize) -> Vec<F> {
+pub fn decompose_fe<F: RnsField>(e: F, number_of_limbs: usize, bit_len: usize) -> Vec<F> {
     decompose(fe_to_big(e), number_of_limbs, bit_len)
 }
 
-pub fn decompose<F: FieldExt>(e: big_uint, number_of_limbs: usize, bit_len: usize) -> Vec<F> {
+pub fn decompose<F: RnsField>(e: big_uint, number_of_limbs: usize, bit_len: usize) -> Vec<F> {
     let mut e = e;
     let mask = big_uint::from(1usize).shl(bit_len) - 1usize;
     let limbs: Vec<F> = (0..number_of_limbs)
@@ -25,7 +27,35 @@ pub fn decompose<F: FieldExt>(e: big_uint, number_of_limbs: usize, bit_len: usiz
     limbs
 }
 
-fn compose(input: Vec<big_uint>, bit_len: usize) -> big_uint {
+/// Same limbs as `decompose_fe` but only public parameters decide which bytes are touched,
+/// so the time it takes doesn't depend on the value of `e`
+pub fn decompose_ct<F: FieldExt>(e: F, number_of_limbs: usize, bit_len: usize) -> Vec<F> {
+    // limb must fit into a field element
+    assert!(bit_len < field_modulus::<F>().bits() as usize);
+    let bytes = e.to_bytes();
+    let number_of_bits = bytes.len() * 8;
+
+    (0..number_of_limbs)
+        .map(|i| {
+            let mut limb = [0u8; 32];
+            for j in 0..bit_len {
+                let k = i * bit_len + j;
+                if k < number_of_bits {
+                    let bit = (bytes[k / 8] >> (k % 8)) & 1;
+                    limb[j / 8] |= bit << (j % 8);
+                }
+            }
+            F::from_bytes(&limb).unwrap()
+        })
+        .collect()
+}
+
+fn decompose_big(e: big_uint, number_of_limbs: usize, bit_len: usize) -> Vec<big_uint> {
+    let mask = (big_uint::one() << bit_len) - 1usize;
+    (0..number_of_limbs).map(|i| (e.clone() >> (bit_len * i)) & mask.clone()).collect()
+}
+
+pub(crate) fn compose(input: Vec<big_uint>, bit_len: usize) -> big_uint {
     let mut e = big_uint::zero();
     for (i, limb) in input.iter().enumerate() {
         e += limb << (bit_len * i)
@@ -33,7 +63,7 @@ fn compose(input: Vec<big_uint>, bit_len: usize) -> big_uint {
     e
 }
 
-fn compose_fe<F: FieldExt>(input: Vec<F>, bit_len: usize) -> big_uint {
+fn compose_fe<F: RnsField>(input: Vec<F>, bit_len: usize) -> big_uint {
     let mut e = big_uint::zero();
     for (i, limb) in input.iter().enumerate() {
         e += fe_to_big(*limb) << (bit_len * i)
@@ -45,25 +75,86 @@ pub trait Common<F: FieldExt> {
     fn value(&self) -> big_uint;
 
     fn native(&self) -> F {
-        let native_value = self.value() % modulus::<F>();
+        let native_value = self.value() % field_modulus::<F>();
         big_to_fe(native_value)
     }
 
     fn eq(&self, other: &Self) -> bool {
         self.value() == other.value()
     }
+
+    fn bits(&self) -> u64 {
+        self.value().bits()
+    }
+}
+
+/// Prime field view that conversions between field elements and limbs rely on, so that they can be
+/// used with field types other than `FieldExt`
+pub trait RnsField: Copy {
+    type Bytes: AsRef<[u8]>;
+
+    fn modulus() -> big_uint;
+
+    /// Little endian canonical byte representation
+    fn bytes(&self) -> Self::Bytes;
+
+    /// Parses a decimal string that is less than the modulus
+    fn from_str(s: &str) -> Option<Self>;
+}
+
+impl<F: FieldExt> RnsField for F {
+    type Bytes = [u8; 32];
+
+    fn modulus() -> big_uint {
+        big_uint::from_str_radix(&F::MODULUS[2..], 16).unwrap()
+    }
+
+    fn bytes(&self) -> [u8; 32] {
+        self.to_bytes()
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        F::from_str_vartime(s)
+    }
+}
+
+/// Adapter of an arkworks prime field to `RnsField`
+#[cfg(feature = "arkworks")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ark<F: ark_ff::PrimeField>(pub F);
+
+#[cfg(feature = "arkworks")]
+impl<F: ark_ff::PrimeField> RnsField for Ark<F> {
+    type Bytes = Vec<u8>;
+
+    fn modulus() -> big_uint {
+        use ark_ff::{BigInteger, FpParameters};
+        big_uint::from_bytes_le(&F::Params::MODULUS.to_bytes_le())
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        use ark_ff::BigInteger;
+        self.0.into_repr().to_bytes_le()
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        s.parse::<F>().ok().map(Ark)
+    }
 }
 
-pub fn fe_to_big<F: FieldExt>(fe: F) -> big_uint {
-    big_uint::from_bytes_le(&fe.to_bytes()[..])
+pub fn fe_to_big<F: RnsField>(fe: F) -> big_uint {
+    big_uint::from_bytes_le(fe.bytes().as_ref())
 }
 
-fn modulus<F: FieldExt>() -> big_uint {
-    big_uint::from_str_radix(&F::MODULUS[2..], 16).unwrap()
+/// Modulus of the field
+pub fn field_modulus<F: RnsField>() -> big_uint {
+    F::modulus()
 }
 
-pub fn big_to_fe<F: FieldExt>(e: big_uint) -> F {
-    F::from_str_vartime(&e.to_str_radix(10)[..]).unwrap()
+/// Values at or above the field modulus are reduced before conversion
+pub fn big_to_fe<F: RnsField>(e: big_uint) -> F {
+    let e = e % field_modulus::<F>();
+    F::from_str(&e.to_str_radix(10)[..]).unwrap()
 }
 
 impl<N: FieldExt> From<Integer<N>> for big_uint {
@@ -87,26 +178,204 @@ impl<F: FieldExt> From<Limb<F>> for big_uint {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) enum Quotient<F: FieldExt> {
+pub enum Quotient<F: FieldExt> {
     Short(F),
     Long(Integer<F>),
 }
 
+/// Witness of a reducing operation: result, quotient, intermediate values and residues
 #[derive(Debug, Clone)]
-pub(crate) struct ReductionContext<N: FieldExt> {
+pub struct ReductionContext<N: FieldExt> {
     pub result: Integer<N>,
     pub quotient: Quotient<N>,
     pub t: Vec<N>,
-    pub negative_modulus: Vec<N>,
     pub u_0: N,
     pub u_1: N,
     pub v_0: N,
     pub v_1: N,
 }
 
+impl<N: FieldExt> ReductionContext<N> {
+    /// Returns named witness values of the reduction independent of any gate layout
+    pub fn to_assignments(&self) -> Vec<(&'static str, N)> {
+        const RESULT: [&str; NUMBER_OF_LIMBS] = ["result_0", "result_1", "result_2", "result_3"];
+        const QUOTIENT: [&str; NUMBER_OF_LIMBS] = ["quotient_0", "quotient_1", "quotient_2", "quotient_3"];
+        const T: [&str; NUMBER_OF_LIMBS] = ["t_0", "t_1", "t_2", "t_3"];
+
+        let mut assignments: Vec<(&'static str, N)> = Vec::new();
+
+        match &self.quotient {
+            Quotient::Short(quotient) => assignments.push(("quotient", *quotient)),
+            Quotient::Long(quotient) => assignments.extend(QUOTIENT.iter().zip(quotient.limbs()).map(|(name, limb)| (*name, limb))),
+        }
+        assignments.extend(RESULT.iter().zip(self.result.limbs()).map(|(name, limb)| (*name, limb)));
+        assignments.extend(T.iter().zip(self.t.iter()).map(|(name, t)| (*name, *t)));
+        assignments.extend(vec![("u_0", self.u_0), ("u_1", self.u_1), ("v_0", self.v_0), ("v_1", self.v_1)]);
+
+        assignments
+    }
+}
+
+/// Multiplier with limbs and value computed once, see `Rns::prepare`
+#[derive(Debug, Clone)]
+pub struct PreparedMultiplier<N: FieldExt> {
+    integer: Integer<N>,
+    value: big_uint,
+}
+
+impl<N: FieldExt> PreparedMultiplier<N> {
+    pub fn integer(&self) -> &Integer<N> {
+        &self.integer
+    }
+}
+
+/// Worst case bit sizes of residues and most significant limbs for a limb width
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowReport {
+    pub mul_v0: usize,
+    pub mul_v1: usize,
+    pub red_v0: usize,
+    pub red_v1: usize,
+    pub max_most_significant_reduced_limb: usize,
+    pub max_most_significant_unreduced_limb: usize,
+    pub max_most_significant_mul_quotient_limb: usize,
+}
+
 pub(crate) struct ComparisionResult<N: FieldExt> {
     pub result: Integer<N>,
     pub borrow: [bool; NUMBER_OF_LIMBS],
+    /// Borrow out of the most significant limb, `true` means `integer > modulus - 1`
+    pub top_borrow: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RnsError {
+    UnexpectedEnd,
+    InvalidFieldElement,
+    InvalidParameter(&'static str),
+    /// Limb width is not a multiple of the number of lookup limbs
+    LimbNotLookupAligned,
+    /// Binary modulus `2^(bit_len_limb * NUMBER_OF_LIMBS)` does not exceed the wrong modulus
+    BinaryModulusTooSmall,
+    /// Lower limbs already cover the wrong modulus so the most significant limb is empty
+    MostSignificantLimbEmpty,
+    /// Product of binary and native modulus does not exceed the square of the max operand
+    CrtModulusTooSmall,
+    /// Sum of limb products may wrap around the native modulus
+    IntermediateOverflow,
+    /// None of the searched limb widths is valid for the field pair
+    NoValidLimbLength,
+}
+
+/// Checks that a wrong field can be emulated over a native field with limbs of `bit_len_limb` bits.
+/// Only moduli are needed so that field pairs without a `FieldExt` implementation can be examined.
+pub fn check_moduli(wrong_modulus: &big_uint, native_modulus: &big_uint, bit_len_limb: usize) -> Result<(), RnsError> {
+    let bit_len_prenormalized = wrong_modulus.bits() as usize;
+
+    let check = |truth: bool, err: RnsError| if truth { Ok(()) } else { Err(err) };
+    check(bit_len_limb % NUMBER_OF_LOOKUP_LIMBS == 0, RnsError::LimbNotLookupAligned)?;
+    let binary_modulus = big_uint::one() << (bit_len_limb * NUMBER_OF_LIMBS);
+    check(binary_modulus > *wrong_modulus, RnsError::BinaryModulusTooSmall)?;
+    check(bit_len_limb * (NUMBER_OF_LIMBS - 1) < bit_len_prenormalized, RnsError::MostSignificantLimbEmpty)?;
+    let max_operand = (big_uint::one() << bit_len_prenormalized) - 1usize;
+    check(binary_modulus * native_modulus > max_operand.clone() * max_operand, RnsError::CrtModulusTooSmall)?;
+    // t_k sums up to NUMBER_OF_LIMBS products of operand limbs and as many of quotient and modulus limbs
+    let max_intermediate = (big_uint::one() << (2 * bit_len_limb)) * (2 * NUMBER_OF_LIMBS);
+    check(max_intermediate < *native_modulus, RnsError::IntermediateOverflow)
+}
+
+/// Parameters of the rns that are derived from the moduli alone, so that they can be constructed
+/// for any `RnsField` pair including fields without a `FieldExt` implementation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RnsParams {
+    pub wrong_modulus: big_uint,
+    pub native_modulus: big_uint,
+    pub bit_len_limb: usize,
+    pub bit_len_lookup: usize,
+    pub bit_len_prenormalized: usize,
+    /// Limbs of `2^(bit_len_limb * NUMBER_OF_LIMBS) - wrong_modulus`
+    pub negative_wrong_modulus: Vec<big_uint>,
+    pub wrong_modulus_decomposed: Vec<big_uint>,
+    pub limb_max_val: big_uint,
+    pub most_significant_limb_max_val: big_uint,
+    pub two_limb_mask: big_uint,
+}
+
+impl RnsParams {
+    pub fn construct<W: RnsField, N: RnsField>(bit_len_limb: usize) -> Result<Self, RnsError> {
+        let wrong_modulus = field_modulus::<W>();
+        let native_modulus = field_modulus::<N>();
+        check_moduli(&wrong_modulus, &native_modulus, bit_len_limb)?;
+
+        let bit_len_prenormalized = wrong_modulus.bits() as usize;
+        let t = big_uint::one() << (bit_len_limb * NUMBER_OF_LIMBS);
+        let negative_wrong_modulus = decompose_big(t - wrong_modulus.clone(), NUMBER_OF_LIMBS, bit_len_limb);
+        let wrong_modulus_decomposed = decompose_big(wrong_modulus.clone(), NUMBER_OF_LIMBS, bit_len_limb);
+        let most_significant_limb_bit_len = bit_len_prenormalized - (bit_len_limb * (NUMBER_OF_LIMBS - 1));
+
+        Ok(RnsParams {
+            wrong_modulus,
+            native_modulus,
+            bit_len_limb,
+            bit_len_lookup: bit_len_limb / NUMBER_OF_LOOKUP_LIMBS,
+            bit_len_prenormalized,
+            negative_wrong_modulus,
+            wrong_modulus_decomposed,
+            limb_max_val: (big_uint::one() << bit_len_limb) - 1usize,
+            most_significant_limb_max_val: (big_uint::one() << most_significant_limb_bit_len) - 1usize,
+            two_limb_mask: (big_uint::one() << (bit_len_limb * 2)) - 1usize,
+        })
+    }
+
+    /// Multiplies in the wrong field following the limb relations of the multiplication gate, with
+    /// intermediate values taken modulo the native modulus as they would be in the circuit
+    pub fn mul(&self, a: &big_uint, b: &big_uint) -> Result<big_uint, ResidueError> {
+        let native = &self.native_modulus;
+        let (quotient, result) = (a * b).div_rem(&self.wrong_modulus);
+
+        let decompose = |e: &big_uint| decompose_big(e.clone(), NUMBER_OF_LIMBS, self.bit_len_limb);
+        let (a_limbs, b_limbs, q_limbs, r_limbs) = (decompose(a), decompose(b), decompose(&quotient), decompose(&result));
+
+        let t: Vec<big_uint> = (0..NUMBER_OF_LIMBS)
+            .map(|k| {
+                (0..=k).fold(big_uint::zero(), |t, i| {
+                    let j = k - i;
+                    t + &a_limbs[i] * &b_limbs[j] + &self.negative_wrong_modulus[i] * &q_limbs[j]
+                }) % native
+            })
+            .collect();
+
+        let s = big_uint::one() << self.bit_len_limb;
+        let sub = |x: big_uint, y: big_uint| (x + native - (y % native)) % native;
+        let u_0 = sub(&t[0] + &s * &t[1], &r_limbs[0] + &s * &r_limbs[1]);
+        let u_1 = sub(&t[2] + &s * &t[3], &r_limbs[2] + &s * &r_limbs[3]);
+
+        if !(&u_0 & &self.two_limb_mask).is_zero() {
+            return Err(ResidueError::U0);
+        }
+        let v_0 = u_0 >> (2 * self.bit_len_limb);
+        if !(((u_1 + v_0) % native) & &self.two_limb_mask).is_zero() {
+            return Err(ResidueError::U1);
+        }
+
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LimbError {
+    WrongNumberOfLimbs(usize),
+    /// Limb at the index exceeds its bound
+    LimbTooWide(usize),
+}
+
+/// Residue whose lower two limbs are not zero in a multiplication witness
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResidueError {
+    /// `u_0` is not a multiple of `2^(2 * bit_len_limb)`
+    U0,
+    /// `u_1` with the carry of `u_0` is not a multiple of `2^(2 * bit_len_limb)`
+    U1,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -128,6 +397,7 @@ pub struct Rns<Wrong: FieldExt, Native: FieldExt> {
     pub limb_max_val: big_uint,
     pub most_significant_limb_max_val: big_uint,
     native_modulus: big_uint,
+    negative_wrong_modulus_big: Vec<big_uint>,
     two_limb_mask: big_uint,
     _marker_wrong: PhantomData<Wrong>,
 }
@@ -136,7 +406,7 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
     fn aux(bit_len_limb: usize) -> Integer<N> {
         let two = N::from_u64(2);
         let r = &fe_to_big(two.pow(&[bit_len_limb as u64, 0, 0, 0]));
-        let wrong_modulus = modulus::<W>();
+        let wrong_modulus = field_modulus::<W>();
         let wrong_modulus_decomposed = Integer::<N>::from_big(wrong_modulus.clone(), NUMBER_OF_LIMBS, bit_len_limb);
         let wrong_modulus_top = wrong_modulus_decomposed.limb(NUMBER_OF_LIMBS - 1).value();
         let range_correct_factor: big_uint = r.div(wrong_modulus_top) + 1usize;
@@ -165,14 +435,45 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
 
         let aux = Integer {
             limbs: aux.iter().map(|aux_limb| Limb::from_big(aux_limb.clone())).collect(),
+            bit_len_limb: Some(bit_len_limb),
         };
 
         aux
     }
 
-    pub(crate) fn construct(bit_len_limb: usize) -> Self {
-        let bit_len_crt_modulus = bit_len_limb * NUMBER_OF_LIMBS;
-        let bit_len_lookup = bit_len_limb / NUMBER_OF_LOOKUP_LIMBS;
+    /// Base aux shifted just enough that each limb dominates the corresponding `max_vals`.
+    /// All limbs take the same shift so that the aux stays a multiple of the wrong modulus
+    pub fn make_aux(&self, max_vals: &[big_uint; NUMBER_OF_LIMBS]) -> Integer<N> {
+        let base_aux: Vec<big_uint> = self.aux.limbs().into_iter().map(fe_to_big).collect();
+        let shift = base_aux
+            .iter()
+            .zip(max_vals.iter())
+            .map(|(aux_limb, max_val)| {
+                assert!(!aux_limb.is_zero(), "aux limb cannot be zero");
+                let mut shift = 0usize;
+                while (aux_limb << shift) < *max_val {
+                    shift += 1;
+                }
+                shift
+            })
+            .max()
+            .unwrap();
+
+        let aux = Integer {
+            limbs: base_aux.iter().map(|aux_limb| Limb::from_big(aux_limb << shift)).collect(),
+            bit_len_limb: Some(self.bit_len_limb),
+        };
+        assert!((aux.value() % &self.wrong_modulus).is_zero());
+        aux
+    }
+
+    pub fn construct(bit_len_limb: usize) -> Self {
+        Self::try_construct(bit_len_limb).unwrap()
+    }
+
+    pub fn try_construct(bit_len_limb: usize) -> Result<Self, RnsError> {
+        let params = RnsParams::construct::<W, N>(bit_len_limb)?;
+
         let two = N::from_u64(2);
         let two_inv = two.invert().unwrap();
         let right_shifter_r = two_inv.pow(&[bit_len_limb as u64, 0, 0, 0]);
@@ -180,24 +481,27 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
         let left_shifter_r = two.pow(&[bit_len_limb as u64, 0, 0, 0]);
         let left_shifter_2r = two.pow(&[2 * bit_len_limb as u64, 0, 0, 0]);
         let left_shifter_3r = two.pow(&[3 * bit_len_limb as u64, 0, 0, 0]);
-        let wrong_modulus = modulus::<W>();
-        let native_modulus = modulus::<N>();
-        let wrong_modulus_in_native_modulus: N = big_to_fe(wrong_modulus.clone() % native_modulus.clone());
-        let t = big_uint::one() << bit_len_crt_modulus;
-        let negative_wrong_modulus = decompose(t - wrong_modulus.clone(), NUMBER_OF_LIMBS, bit_len_limb);
-        let wrong_modulus_decomposed = decompose(wrong_modulus.clone(), NUMBER_OF_LIMBS, bit_len_limb);
+        let wrong_modulus_in_native_modulus: N = big_to_fe(params.wrong_modulus.clone() % params.native_modulus.clone());
+        let negative_wrong_modulus = params.negative_wrong_modulus.iter().map(|limb| big_to_fe(limb.clone())).collect();
+        let wrong_modulus_decomposed = params.wrong_modulus_decomposed.iter().map(|limb| big_to_fe(limb.clone())).collect();
 
-        let wrong_modulus_minus_one = Integer::<N>::from_big(wrong_modulus.clone() - 1usize, NUMBER_OF_LIMBS, bit_len_limb);
+        let wrong_modulus_minus_one = Integer::<N>::from_big(params.wrong_modulus.clone() - 1usize, NUMBER_OF_LIMBS, bit_len_limb);
 
-        let two_limb_mask = (big_uint::one() << (bit_len_limb * 2)) - 1usize;
         let aux = Self::aux(bit_len_limb);
 
-        let limb_max_val = (big_uint::one() << bit_len_limb) - 1usize;
-        let bit_len_prenormalized = wrong_modulus.bits() as usize;
-        let most_significant_limb_bit_len = bit_len_prenormalized - (bit_len_limb * (NUMBER_OF_LIMBS - 1));
-        let most_significant_limb_max_val = (big_uint::one() << most_significant_limb_bit_len) - 1usize;
+        let RnsParams {
+            wrong_modulus,
+            native_modulus,
+            bit_len_lookup,
+            bit_len_prenormalized,
+            negative_wrong_modulus: negative_wrong_modulus_big,
+            limb_max_val,
+            most_significant_limb_max_val,
+            two_limb_mask,
+            ..
+        } = params;
 
-        Rns {
+        Ok(Rns {
             right_shifter_r,
             right_shifter_2r,
             left_shifter_r,
@@ -206,6 +510,7 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
             wrong_modulus,
             native_modulus,
             negative_wrong_modulus,
+            negative_wrong_modulus_big,
             wrong_modulus_decomposed,
             wrong_modulus_minus_one,
             wrong_modulus_in_native_modulus,
@@ -217,6 +522,85 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
             limb_max_val,
             most_significant_limb_max_val,
             _marker_wrong: PhantomData,
+        })
+    }
+
+    /// Picks the limb width among the valid ones that requires the least overflow bits in range checks
+    pub fn construct_auto() -> Result<Self, RnsError> {
+        let cost = |bit_len_limb: usize| -> usize {
+            let report = Self::emulate_overflows(bit_len_limb);
+            let bit_len_lookup = bit_len_limb / NUMBER_OF_LOOKUP_LIMBS;
+            let mut overflow_lengths: Vec<usize> = vec![
+                report.mul_v0,
+                report.mul_v1,
+                report.red_v0,
+                report.red_v1,
+                report.max_most_significant_reduced_limb,
+                report.max_most_significant_unreduced_limb,
+                report.max_most_significant_mul_quotient_limb,
+            ]
+            .iter()
+            .map(|bit_len| bit_len % bit_len_lookup)
+            .collect();
+            overflow_lengths.sort();
+            overflow_lengths.dedup();
+            overflow_lengths.iter().sum()
+        };
+
+        (60..=90)
+            .filter_map(|bit_len_limb| Self::try_construct(bit_len_limb).ok())
+            .min_by_key(|rns| cost(rns.bit_len_limb))
+            .ok_or(RnsError::NoValidLimbLength)
+    }
+
+    /// Emulates the largest residues of multiplication and reduction without constructing the rns
+    pub fn emulate_overflows(bit_len_limb: usize) -> OverflowReport {
+        let wrong_modulus = field_modulus::<W>();
+        let bit_len_prenormalized = wrong_modulus.bits() as usize;
+        let r = big_uint::one() << bit_len_limb;
+        let limb_max = r.clone() - 1usize;
+        let negative_modulus = decompose_big((big_uint::one() << (bit_len_limb * NUMBER_OF_LIMBS)) - wrong_modulus.clone(), NUMBER_OF_LIMBS, bit_len_limb);
+
+        // limb bounds of an integer that is bounded by `max`, lower limbs may be full
+        let limb_bounds = |max: &big_uint| -> Vec<big_uint> {
+            let top = max.clone() >> (bit_len_limb * (NUMBER_OF_LIMBS - 1));
+            let mut limbs = vec![limb_max.clone(); NUMBER_OF_LIMBS - 1];
+            limbs.push(top);
+            limbs
+        };
+        let bits = |e: big_uint| e.bits() as usize;
+
+        // residues v_0 and v_1 of intermediate values, result limbs are subtracted so they are omitted
+        let residues = |t: Vec<big_uint>| -> (big_uint, big_uint) {
+            let v_0 = (t[0].clone() + t[1].clone() * r.clone()) >> (2 * bit_len_limb);
+            let v_1 = (t[2].clone() + t[3].clone() * r.clone() + v_0.clone()) >> (2 * bit_len_limb);
+            (v_0, v_1)
+        };
+
+        // multiplication of two operands in the bit length of the wrong modulus
+        let max_operand = (big_uint::one() << bit_len_prenormalized) - 1usize;
+        let max_mul_quotient = (max_operand.clone() * max_operand.clone()) / wrong_modulus.clone();
+        let operand = limb_bounds(&max_operand);
+        let quotient = limb_bounds(&max_mul_quotient);
+        let t: Vec<big_uint> = (0..NUMBER_OF_LIMBS)
+            .map(|k| (0..=k).map(|i| operand[i].clone() * operand[k - i].clone() + negative_modulus[i].clone() * quotient[k - i].clone()).sum())
+            .collect();
+        let (mul_v0, mul_v1) = residues(t);
+
+        // reduction of an unreduced integer with a single limb quotient
+        let max_value = (wrong_modulus.clone() << bit_len_limb) - 1usize;
+        let max_unreduced_limb = max_value / compose(vec![big_uint::one(); NUMBER_OF_LIMBS], bit_len_limb);
+        let t: Vec<big_uint> = (0..NUMBER_OF_LIMBS).map(|k| max_unreduced_limb.clone() + negative_modulus[k].clone() * limb_max.clone()).collect();
+        let (red_v0, red_v1) = residues(t);
+
+        OverflowReport {
+            mul_v0: bits(mul_v0),
+            mul_v1: bits(mul_v1),
+            red_v0: bits(red_v0),
+            red_v1: bits(red_v1),
+            max_most_significant_reduced_limb: bit_len_prenormalized - bit_len_limb * (NUMBER_OF_LIMBS - 1),
+            max_most_significant_unreduced_limb: bits(max_unreduced_limb),
+            max_most_significant_mul_quotient_limb: bits(quotient[NUMBER_OF_LIMBS - 1].clone()),
         }
     }
 
@@ -224,9 +608,56 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
         Integer::from_big(fe_to_big(fe), NUMBER_OF_LIMBS, self.bit_len_limb)
     }
 
+    /// Value of the integer as an element of the wrong field
+    pub fn to_wrong_field(&self, a: &Integer<N>) -> W {
+        big_to_fe(self.value(a) % &self.wrong_modulus)
+    }
+
+    /// Decomposes canonical little endian bytes of a wrong field element without constructing the element
+    pub fn new_from_repr(&self, repr: &[u8; 32]) -> Integer<N> {
+        let e = big_uint::from_bytes_le(&repr[..]);
+        assert!(e < self.wrong_modulus, "repr is not canonical");
+        self.new_from_big(e)
+    }
+
     pub(crate) fn new_from_limbs(&self, limbs: Vec<N>) -> Integer<N> {
         let limbs = limbs.iter().map(|limb| Limb::<N>::new(*limb)).collect();
-        Integer { limbs }
+        Integer {
+            limbs,
+            bit_len_limb: Some(self.bit_len_limb),
+        }
+    }
+
+    /// Wrong modulus as an integer, e.g. to be assigned as a constant
+    pub fn wrong_modulus_integer(&self) -> Integer<N> {
+        self.new_from_limbs(self.wrong_modulus_decomposed.clone())
+    }
+
+    /// `2^(bit_len_limb * NUMBER_OF_LIMBS) - wrong_modulus` as an integer
+    pub fn neg_wrong_modulus_integer(&self) -> Integer<N> {
+        self.new_from_limbs(self.negative_wrong_modulus.clone())
+    }
+
+    /// Like `new_from_limbs` but each limb must fit in `bit_len_limb` bits and the most significant
+    /// one in the bit length that remains of the wrong modulus
+    pub fn new_from_limbs_checked(&self, limbs: Vec<N>) -> Result<Integer<N>, LimbError> {
+        if limbs.len() != NUMBER_OF_LIMBS {
+            return Err(LimbError::WrongNumberOfLimbs(limbs.len()));
+        }
+        for (idx, limb) in limbs.iter().enumerate() {
+            let max_val = if idx == NUMBER_OF_LIMBS - 1 { &self.most_significant_limb_max_val } else { &self.limb_max_val };
+            if fe_to_big(*limb) > *max_val {
+                return Err(LimbError::LimbTooWide(idx));
+            }
+        }
+        Ok(self.new_from_limbs(limbs))
+    }
+
+    pub(crate) fn new_from_limb_structs(&self, limbs: Vec<Limb<N>>) -> Integer<N> {
+        Integer {
+            limbs,
+            bit_len_limb: Some(self.bit_len_limb),
+        }
     }
 
     pub(crate) fn new_from_big(&self, e: big_uint) -> Integer<N> {
@@ -234,12 +665,38 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
         self.new_from_limbs(limbs)
     }
 
-    #[cfg(test)]
+    /// Bits beyond the dense limbs are kept in the most significant limb so the value can be reduced later
+    pub fn new_from_big_unreduced(&self, e: big_uint) -> Integer<N> {
+        let shift = self.bit_len_limb * (NUMBER_OF_LIMBS - 1);
+        let mut limbs = decompose_big(e.clone(), NUMBER_OF_LIMBS - 1, self.bit_len_limb);
+        let most_significant_limb = e >> shift;
+        assert!(
+            most_significant_limb <= self.max_unreduced_limb(),
+            "most significant limb exceeds the largest reducible limb"
+        );
+        limbs.push(most_significant_limb);
+        let limbs = limbs.into_iter().map(|limb| limb.into()).collect();
+        Integer {
+            limbs,
+            bit_len_limb: Some(self.bit_len_limb),
+        }
+    }
+
+    /// Parses a hex string with or without `0x` prefix, value must fit into the limbs
+    pub fn new_from_str(&self, hex: &str) -> Integer<N> {
+        let hex = hex.trim_start_matches("0x");
+        let e = big_uint::from_str_radix(hex, 16).expect("invalid hex string");
+        let max_bit_len = self.bit_len_limb * NUMBER_OF_LIMBS;
+        assert!(e.bits() as usize <= max_bit_len, "value does not fit into {} bits", max_bit_len);
+        self.new_from_big(e)
+    }
+
+    #[cfg(any(test, feature = "testing"))]
     pub(crate) fn rand_normalized(&self) -> Integer<N> {
         self.new_from_big(fe_to_big(W::rand()))
     }
 
-    #[cfg(test)]
+    #[cfg(any(test, feature = "testing"))]
     pub(crate) fn rand_prenormalized(&self) -> Integer<N> {
         use num_bigint::RandBigInt;
         use rand::thread_rng;
@@ -261,7 +718,79 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
             })
             .collect();
 
-        Integer { limbs }
+        Integer {
+            limbs,
+            bit_len_limb: Some(self.bit_len_limb),
+        }
+    }
+
+    /// Random integer below the wrong modulus
+    #[cfg(any(test, feature = "testing"))]
+    pub fn rand_in_remainder_range(&self) -> Integer<N> {
+        self.rand_normalized()
+    }
+
+    /// Random integer that fits in the bit length of the wrong modulus
+    #[cfg(any(test, feature = "testing"))]
+    pub fn rand_in_operand_range(&self) -> Integer<N> {
+        self.rand_prenormalized()
+    }
+
+    /// Random integer with limbs up to the largest reducible limb value
+    #[cfg(any(test, feature = "testing"))]
+    pub fn rand_in_unreduced_range(&self) -> Integer<N> {
+        use num_bigint::RandBigInt;
+        use rand::thread_rng;
+        let mut rng = thread_rng();
+        let bound = self.max_unreduced_limb() + 1usize;
+        let limbs = (0..NUMBER_OF_LIMBS).map(|_| rng.gen_biguint_below(&bound).into()).collect();
+        Integer {
+            limbs,
+            bit_len_limb: Some(self.bit_len_limb),
+        }
+    }
+
+    /// Largest integer below the wrong modulus
+    #[cfg(any(test, feature = "testing"))]
+    pub fn max_in_remainder_range(&self) -> Integer<N> {
+        self.wrong_modulus_minus_one.clone()
+    }
+
+    /// Largest integer that fits in the bit length of the wrong modulus
+    #[cfg(any(test, feature = "testing"))]
+    pub fn max_in_operand_range(&self) -> Integer<N> {
+        self.new_from_big((big_uint::one() << self.bit_len_prenormalized) - 1usize)
+    }
+
+    pub fn wrong_field_modulus(&self) -> &big_uint {
+        &self.wrong_modulus
+    }
+
+    pub fn native_field_modulus(&self) -> &big_uint {
+        &self.native_modulus
+    }
+
+    /// Largest limb value of a reduced integer
+    pub fn max_reduced_limb(&self) -> big_uint {
+        self.limb_max_val.clone()
+    }
+
+    /// Largest value a multiplication operand may take without a prior reduction
+    pub fn max_operand(&self) -> big_uint {
+        compose(vec![self.max_reduced_limb(); NUMBER_OF_LIMBS], self.bit_len_limb)
+    }
+
+    /// Largest limb value that can still be reduced, reduction quotient must fit in a single limb
+    pub fn max_unreduced_limb(&self) -> big_uint {
+        let max_value = (self.wrong_modulus.clone() << self.bit_len_limb) - 1usize;
+        let all_ones = compose(vec![big_uint::one(); NUMBER_OF_LIMBS], self.bit_len_limb);
+        max_value / all_ones
+    }
+
+    /// Number of additions of reduced integers that can be chained before a reduction is required
+    pub fn max_additions_before_reduce(&self) -> usize {
+        let max_additions = self.max_unreduced_limb() / self.max_reduced_limb() - 1usize;
+        max_additions.to_usize().unwrap_or(usize::MAX)
     }
 
     pub(crate) fn value(&self, a: &Integer<N>) -> big_uint {
@@ -269,8 +798,12 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
     }
 
     pub(crate) fn compare_to_modulus(&self, integer: &Integer<N>) -> ComparisionResult<N> {
+        self.compare_to(integer, &self.wrong_modulus_minus_one)
+    }
+
+    pub(crate) fn compare_to(&self, integer: &Integer<N>, bound_minus_one: &Integer<N>) -> ComparisionResult<N> {
         let mut borrow = [false; NUMBER_OF_LIMBS];
-        let modulus_minus_one = self.wrong_modulus_minus_one.clone();
+        let modulus_minus_one = bound_minus_one.clone();
 
         let mut prev_borrow = big_uint::zero();
         let limbs: Vec<N> = integer
@@ -292,36 +825,119 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
             .collect();
 
         let result = self.new_from_limbs(limbs);
+        let top_borrow = borrow[NUMBER_OF_LIMBS - 1];
+
+        ComparisionResult { result, borrow, top_borrow }
+    }
+
+    /// Limb-wise `a - b` with per limb borrows, last borrow is set when `a < b`
+    pub fn sub_with_borrow(&self, a: &Integer<N>, b: &Integer<N>) -> (Integer<N>, [bool; NUMBER_OF_LIMBS], bool) {
+        let ComparisionResult { result, borrow, top_borrow } = self.compare_to(b, a);
+        (result, borrow, top_borrow)
+    }
 
-        ComparisionResult { result, borrow }
+    fn assert_same_limb_width(&self, integer: &Integer<N>) {
+        if let Some(bit_len_limb) = integer.bit_len_limb {
+            assert_eq!(
+                bit_len_limb, self.bit_len_limb,
+                "integer with {} bit limbs is used with rns of {} bit limbs",
+                bit_len_limb, self.bit_len_limb
+            );
+        }
     }
 
     pub(crate) fn mul(&self, integer_0: &Integer<N>, integer_1: &Integer<N>) -> ReductionContext<N> {
-        let modulus = self.wrong_modulus.clone();
-        let negative_modulus = self.negative_wrong_modulus.clone();
+        self.assert_same_limb_width(integer_1);
+        self.mul_with_value(integer_0, integer_1, &self.value(integer_1))
+    }
+
+    /// Returns true if `a * b` and `expected` are congruent modulo the wrong modulus
+    pub fn mul_and_check(&self, a: &Integer<N>, b: &Integer<N>, expected: &Integer<N>) -> bool {
+        (self.value(a) * self.value(b)) % &self.wrong_modulus == self.value(expected) % &self.wrong_modulus
+    }
+
+    /// Caches limbs and value of a multiplier that is used with many operands
+    pub fn prepare(&self, integer: &Integer<N>) -> PreparedMultiplier<N> {
+        self.assert_same_limb_width(integer);
+        PreparedMultiplier {
+            integer: integer.clone(),
+            value: self.value(integer),
+        }
+    }
 
-        let (quotient, result) = (self.value(integer_0) * self.value(integer_1)).div_rem(&modulus);
+    /// Same witness as `mul` with the value of the multiplier taken from the cache
+    pub fn mul_prepared(&self, integer: &Integer<N>, multiplier: &PreparedMultiplier<N>) -> ReductionContext<N> {
+        self.mul_with_value(integer, &multiplier.integer, &multiplier.value)
+    }
+
+    fn mul_with_value(&self, integer_0: &Integer<N>, integer_1: &Integer<N>, value_1: &big_uint) -> ReductionContext<N> {
+        let reduction_context = self.mul_context_unchecked(integer_0, integer_1, value_1);
+
+        #[cfg(debug_assertions)]
+        self.check_residues(reduction_context.u_0, reduction_context.u_1);
+
+        reduction_context
+    }
+
+    // Witness of the multiplication that checked and fallible variants share, residues are not checked here
+    fn mul_context_unchecked(&self, integer_0: &Integer<N>, integer_1: &Integer<N>, value_1: &big_uint) -> ReductionContext<N> {
+        self.assert_same_limb_width(integer_0);
+
+        let (quotient, result) = (self.value(integer_0) * value_1).div_rem(&self.wrong_modulus);
 
         let quotient = self.new_from_big(quotient);
         let result = self.new_from_big(result);
 
-        let l = NUMBER_OF_LIMBS;
-        let mut t: Vec<N> = vec![N::zero(); l];
-        for k in 0..l {
-            for i in 0..=k {
-                let j = k - i;
-                t[i + j] = t[i + j] + integer_0.limb_value(i) * integer_1.limb_value(j) + negative_modulus[i] * quotient.limb_value(j);
-            }
-        }
+        let t = self.intermediate_values(integer_0, integer_1, &quotient);
 
-        let (u_0, u_1, v_0, v_1) = self.residues(t.clone(), result.clone());
+        #[cfg(debug_assertions)]
+        self.check_intermediate_values(integer_0, integer_1, &quotient);
+
+        let (u_0, u_1, v_0, v_1) = self.residues_unchecked(&t, &result);
         let quotient = Quotient::Long(quotient);
 
-        ReductionContext {
+        let reduction_context = ReductionContext {
             result,
             quotient,
             t,
-            negative_modulus,
+            u_0,
+            u_1,
+            v_0,
+            v_1,
+        };
+
+        #[cfg(debug_assertions)]
+        self.check_mul_context(integer_0, integer_1, &reduction_context);
+
+        reduction_context
+    }
+
+    /// Witness of `a * b` with the given result, the quotient is `(a * b - result) / p` so it is only
+    /// satisfiable if `result` is congruent to `a * b` and not greater than it
+    pub(crate) fn mul_with_result(&self, integer_0: &Integer<N>, integer_1: &Integer<N>, result: &Integer<N>) -> ReductionContext<N> {
+        self.assert_same_limb_width(integer_0);
+        self.assert_same_limb_width(integer_1);
+
+        let product = self.value(integer_0) * self.value(integer_1);
+        let result_value = self.value(result);
+        let quotient = if product >= result_value {
+            (product - result_value) / &self.wrong_modulus
+        } else {
+            big_uint::zero()
+        };
+        let quotient = self.new_from_big(quotient);
+
+        let t = self.intermediate_values(integer_0, integer_1, &quotient);
+
+        #[cfg(debug_assertions)]
+        self.check_intermediate_values(integer_0, integer_1, &quotient);
+
+        let (u_0, u_1, v_0, v_1) = self.residues_unchecked(&t, result);
+
+        ReductionContext {
+            result: result.clone(),
+            quotient: Quotient::Long(quotient),
+            t,
             u_0,
             u_1,
             v_0,
@@ -329,11 +945,30 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
         }
     }
 
+    /// Multiplication by a known constant, limbs of the constant are not witnessed
+    pub(crate) fn mul_constant(&self, integer: &Integer<N>, constant: &big_uint) -> ReductionContext<N> {
+        let constant = self.new_from_big(constant % &self.wrong_modulus);
+        self.mul(integer, &constant)
+    }
+
     pub(crate) fn reduce(&self, integer: &Integer<N>) -> ReductionContext<N> {
-        let modulus = self.wrong_modulus.clone();
-        let negative_modulus = self.negative_wrong_modulus.clone();
+        let negative_modulus = &self.negative_wrong_modulus;
+
+        // zero reduces to itself with a zero quotient so residue machinery is skipped
+        if self.value(integer).is_zero() {
+            let zero = N::zero();
+            return ReductionContext {
+                result: self.new_from_limbs(vec![zero; NUMBER_OF_LIMBS]),
+                quotient: Quotient::Short(zero),
+                t: vec![zero; NUMBER_OF_LIMBS],
+                u_0: zero,
+                u_1: zero,
+                v_0: zero,
+                v_1: zero,
+            };
+        }
 
-        let (quotient, result) = self.value(integer).div_rem(&modulus);
+        let (quotient, result) = self.value(integer).div_rem(&self.wrong_modulus);
         assert!(quotient < big_uint::one() << self.bit_len_limb);
 
         let quotient: N = big_to_fe(quotient);
@@ -358,7 +993,6 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
             result,
             quotient,
             t,
-            negative_modulus,
             u_0,
             u_1,
             v_0,
@@ -366,40 +1000,250 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
         }
     }
 
-    fn residues(&self, t: Vec<N>, r: Integer<N>) -> (N, N, N, N) {
-        let s = self.left_shifter_r;
-
-        let u_0 = t[0] + s * t[1] - r.limb_value(0) - s * r.limb_value(1);
-        let u_1 = t[2] + s * t[3] - r.limb_value(2) - s * r.limb_value(3);
+    /// Reduction of integers whose quotient doesn't fit in a single limb, the integer may also have more
+    /// limbs than `NUMBER_OF_LIMBS`. Limbs are spread into limb sized chunks which are grouped by
+    /// `NUMBER_OF_LIMBS`. The lowest group is reduced, each higher group `k` is multiplied by
+    /// `2^(bit_len_limb * NUMBER_OF_LIMBS * k)` and the sum of all results is reduced again. Last context
+    /// holds the final result.
+    pub(crate) fn reduce_large(&self, integer: &Integer<N>) -> Vec<ReductionContext<N>> {
+        let bit_len_limb = self.bit_len_limb;
+        let quotient = self.value(integer) / &self.wrong_modulus;
+        if integer.limbs().len() == NUMBER_OF_LIMBS && quotient < big_uint::one() << bit_len_limb {
+            return vec![self.reduce(integer)];
+        }
 
-        // sanity check
-        {
-            let mask = self.two_limb_mask.clone();
-            let u_1 = u_0 * self.right_shifter_2r + u_1;
-            let u_0: big_uint = fe_to_big(u_0);
-            let u_1: big_uint = fe_to_big(u_1);
-            assert_eq!(u_0 & mask.clone(), big_uint::zero());
-            assert_eq!(u_1 & mask, big_uint::zero());
+        // a = sum c_k * 2^(bit_len_limb * k) where each c_k is a sum of limb sized chunks
+        let number_of_chunks = (self.native_modulus.bits() as usize + bit_len_limb - 1) / bit_len_limb;
+        let mut chunks = vec![big_uint::zero(); integer.limbs().len() + number_of_chunks - 1];
+        for (i, limb) in integer.limbs().iter().enumerate() {
+            for (j, chunk) in decompose_big(fe_to_big(*limb), number_of_chunks, bit_len_limb).into_iter().enumerate() {
+                chunks[i + j] += chunk;
+            }
+        }
+        let mut chunks: Vec<N> = chunks.into_iter().map(big_to_fe).collect();
+        let number_of_groups = (chunks.len() + NUMBER_OF_LIMBS - 1) / NUMBER_OF_LIMBS;
+        chunks.resize(number_of_groups * NUMBER_OF_LIMBS, N::zero());
+
+        let mut contexts = vec![];
+        for (k, group) in chunks.chunks(NUMBER_OF_LIMBS).enumerate() {
+            let group = self.new_from_limbs(group.to_vec());
+            let context = if k == 0 {
+                self.reduce(&group)
+            } else {
+                let shifter = (big_uint::one() << (bit_len_limb * NUMBER_OF_LIMBS * k)) % &self.wrong_modulus;
+                self.mul_constant(&group, &shifter)
+            };
+            contexts.push(context);
         }
 
-        let v_0 = u_0 * self.right_shifter_2r;
-        let v_1 = (u_1 + v_0) * self.right_shifter_2r;
+        let sum: Vec<N> = (0..NUMBER_OF_LIMBS)
+            .map(|i| contexts.iter().fold(N::zero(), |acc, context| acc + context.result.limb_value(i)))
+            .collect();
+        let sum = self.reduce(&self.new_from_limbs(sum));
+        contexts.push(sum);
 
-        (u_0, u_1, v_0, v_1)
+        contexts
     }
 
-    pub(crate) fn invert(&self, a: &Integer<N>) -> Option<Integer<N>> {
-        let a_biguint = a.value();
-        let a_w = big_to_fe::<W>(a_biguint);
-        let inv_w = a_w.invert();
+    // t_k = sum a_i * b_j + p'_i * q_j where i + j = k
+    #[cfg(not(feature = "rayon"))]
+    fn intermediate_values(&self, integer_0: &Integer<N>, integer_1: &Integer<N>, quotient: &Integer<N>) -> Vec<N> {
+        let negative_modulus = &self.negative_wrong_modulus;
+        let l = NUMBER_OF_LIMBS;
+        let mut t: Vec<N> = vec![N::zero(); l];
+        for k in 0..l {
+            for i in 0..=k {
+                let j = k - i;
+                t[i + j] = t[i + j] + integer_0.limb_value(i) * integer_1.limb_value(j) + negative_modulus[i] * quotient.limb_value(j);
+            }
+        }
+        t
+    }
 
-        inv_w.map(|inv| {
-            self.new_from_big(fe_to_big(inv))
-        }).into()
+    // Each t_k is independent of the others so they are accumulated in parallel
+    #[cfg(feature = "rayon")]
+    fn intermediate_values(&self, integer_0: &Integer<N>, integer_1: &Integer<N>, quotient: &Integer<N>) -> Vec<N> {
+        let negative_modulus = &self.negative_wrong_modulus;
+        (0..NUMBER_OF_LIMBS)
+            .into_par_iter()
+            .map(|k| {
+                (0..=k).fold(N::zero(), |t, i| {
+                    let j = k - i;
+                    t + integer_0.limb_value(i) * integer_1.limb_value(j) + negative_modulus[i] * quotient.limb_value(j)
+                })
+            })
+            .collect()
     }
 
-    pub(crate) fn div(&self, a: &Integer<N>, b: &Integer<N>) -> Option<Integer<N>> {
-        let modulus = self.wrong_modulus.clone();
+    // Intermediate values must not wrap around the native modulus otherwise the witness is silently wrong
+    #[cfg(debug_assertions)]
+    fn check_intermediate_values(&self, integer_0: &Integer<N>, integer_1: &Integer<N>, quotient: &Integer<N>) {
+        let negative_modulus = &self.negative_wrong_modulus_big;
+        for k in 0..NUMBER_OF_LIMBS {
+            let t: big_uint = (0..=k)
+                .map(|i| {
+                    let j = k - i;
+                    integer_0.limb(i).value() * integer_1.limb(j).value() + negative_modulus[i].clone() * quotient.limb(j).value()
+                })
+                .sum();
+            assert!(
+                t < self.native_modulus,
+                "limb product overflow at t_{}: intermediate value {} exceeds native modulus, check limb bit lengths of operands",
+                k,
+                t.to_str_radix(16)
+            );
+        }
+    }
+
+    /// Recomputes the relations of a multiplication witness so that a bug in witness generation panics at
+    /// synthesis rather than leaving an unsatisfiable circuit to be found at proving time
+    #[cfg(debug_assertions)]
+    pub(crate) fn check_mul_context(&self, a: &Integer<N>, b: &Integer<N>, context: &ReductionContext<N>) {
+        let quotient = match &context.quotient {
+            Quotient::Long(quotient) => quotient,
+            _ => panic!("long quotient expected"),
+        };
+        let result = &context.result;
+        let negative_wrong_modulus = &self.negative_wrong_modulus;
+
+        assert!(
+            a.value() * b.value() == quotient.value() * &self.wrong_modulus + result.value(),
+            "witness sanity check failed: a * b != q * p + r"
+        );
+
+        for k in 0..NUMBER_OF_LIMBS {
+            let t = (0..=k).fold(N::zero(), |t, i| {
+                let j = k - i;
+                t + a.limb_value(i) * b.limb_value(j) + negative_wrong_modulus[i] * quotient.limb_value(j)
+            });
+            assert!(t == context.t[k], "witness sanity check failed at t_{}", k);
+        }
+
+        let (t, s) = (&context.t, self.left_shifter_r);
+        let u_0 = t[0] + s * t[1] - result.limb_value(0) - s * result.limb_value(1);
+        let u_1 = t[2] + s * t[3] - result.limb_value(2) - s * result.limb_value(3);
+        assert!(u_0 == context.u_0, "witness sanity check failed at u_0");
+        assert!(u_1 == context.u_1, "witness sanity check failed at u_1");
+
+        let left_shifter_2r = self.left_shifter_2r;
+        assert!(context.v_0 * left_shifter_2r == u_0, "witness sanity check failed at v_0");
+        assert!(context.v_1 * left_shifter_2r == u_1 + context.v_0, "witness sanity check failed at v_1");
+    }
+
+    fn residues(&self, t: Vec<N>, r: Integer<N>) -> (N, N, N, N) {
+        let residues = self.residues_unchecked(&t, &r);
+
+        #[cfg(debug_assertions)]
+        self.check_residues(residues.0, residues.1);
+
+        residues
+    }
+
+    fn residues_unchecked(&self, t: &[N], r: &Integer<N>) -> (N, N, N, N) {
+        let s = self.left_shifter_r;
+
+        let u_0 = t[0] + s * t[1] - r.limb_value(0) - s * r.limb_value(1);
+        let u_1 = t[2] + s * t[3] - r.limb_value(2) - s * r.limb_value(3);
+
+        let v_0 = u_0 * self.right_shifter_2r;
+        let v_1 = (u_1 + v_0) * self.right_shifter_2r;
+
+        (u_0, u_1, v_0, v_1)
+    }
+
+    // Lower two limbs of residues must be zero
+    fn residue_check(&self, u_0: N, u_1: N) -> Result<(), ResidueError> {
+        let mask = &self.two_limb_mask;
+        let u_1 = u_0 * self.right_shifter_2r + u_1;
+        if !(fe_to_big(u_0) & mask).is_zero() {
+            return Err(ResidueError::U0);
+        }
+        if !(fe_to_big(u_1) & mask).is_zero() {
+            return Err(ResidueError::U1);
+        }
+        Ok(())
+    }
+
+    // Skipped in release builds to avoid big integer work in the prover
+    #[cfg(debug_assertions)]
+    fn check_residues(&self, u_0: N, u_1: N) {
+        if let Err(e) = self.residue_check(u_0, u_1) {
+            panic!("residue sanity check failed: {:?}", e);
+        }
+    }
+
+    /// Like `mul` but a failing residue sanity check is returned rather than asserted
+    pub(crate) fn try_mul(&self, integer_0: &Integer<N>, integer_1: &Integer<N>) -> Result<ReductionContext<N>, ResidueError> {
+        self.assert_same_limb_width(integer_1);
+        let reduction_context = self.mul_context_unchecked(integer_0, integer_1, &self.value(integer_1));
+        self.residue_check(reduction_context.u_0, reduction_context.u_1)?;
+        Ok(reduction_context)
+    }
+
+    fn try_reduction_context(&self, t: Vec<N>, result: Integer<N>, quotient: Quotient<N>) -> Result<ReductionContext<N>, ResidueError> {
+        let (u_0, u_1, v_0, v_1) = self.residues_unchecked(&t, &result);
+        self.residue_check(u_0, u_1)?;
+
+        Ok(ReductionContext {
+            result,
+            quotient,
+            t,
+            u_0,
+            u_1,
+            v_0,
+            v_1,
+        })
+    }
+
+    pub(crate) fn invert(&self, a: &Integer<N>) -> Option<Integer<N>> {
+        let a_biguint = a.value();
+        let a_w = big_to_fe::<W>(a_biguint);
+        let inv_w = a_w.invert();
+
+        inv_w.map(|inv| {
+            self.new_from_big(fe_to_big(inv))
+        }).into()
+    }
+
+    /// Square root in the wrong field, `None` if `a` is not a quadratic residue
+    pub fn sqrt(&self, a: &Integer<N>) -> Option<Integer<N>> {
+        let a_w = big_to_fe::<W>(a.value());
+        a_w.sqrt().map(|root| self.new_from_big(fe_to_big(root))).into()
+    }
+
+    /// Returns true if `a` is congruent to zero modulo the wrong modulus
+    pub fn is_zero_mod(&self, a: &Integer<N>) -> bool {
+        (self.value(a) % &self.wrong_modulus).is_zero()
+    }
+
+    /// Legendre symbol of `a` in the wrong field computed as `a^((p-1)/2)`, that is `1` for nonzero
+    /// squares, `-1` for non residues and `0` for values congruent to zero
+    pub fn legendre_symbol(&self, a: &Integer<N>) -> i8 {
+        let modulus = &self.wrong_modulus;
+        let exponent = (modulus - 1usize) >> 1usize;
+        let symbol = (self.value(a) % modulus).modpow(&exponent, modulus);
+        if symbol.is_zero() {
+            0
+        } else if symbol.is_one() {
+            1
+        } else {
+            assert_eq!(symbol, modulus - 1usize);
+            -1
+        }
+    }
+
+    /// Returns true if `a` has a square root in the wrong field, zero included
+    pub fn is_quadratic_residue(&self, a: &Integer<N>) -> bool {
+        self.legendre_symbol(a) >= 0
+    }
+
+    /// Primitive root of unity of order 2^S which is never a square
+    pub(crate) fn non_residue(&self) -> Integer<N> {
+        self.new_from_big(fe_to_big(W::ROOT_OF_UNITY))
+    }
+
+    pub(crate) fn div(&self, a: &Integer<N>, b: &Integer<N>) -> Option<Integer<N>> {
+        let modulus = self.wrong_modulus.clone();
         self.invert(b).map(|b_inv| {
             let a_mul_b = (a.value() * b_inv.value()) % modulus;
             self.new_from_big(a_mul_b)
@@ -407,7 +1251,194 @@ impl<W: FieldExt, N: FieldExt> Rns<W, N> {
     }
 }
 
-#[derive(Debug, Clone)]
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], RnsError> {
+        if self.bytes.len() < len {
+            return Err(RnsError::UnexpectedEnd);
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn read_usize(&mut self) -> Result<usize, RnsError> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(bytes) as usize)
+    }
+
+    fn read_fe<F: FieldExt>(&mut self) -> Result<F, RnsError> {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(self.take(32)?);
+        Option::from(F::from_bytes(&bytes)).ok_or(RnsError::InvalidFieldElement)
+    }
+
+    fn read_fes<F: FieldExt>(&mut self) -> Result<Vec<F>, RnsError> {
+        (0..NUMBER_OF_LIMBS).map(|_| self.read_fe()).collect()
+    }
+
+    fn read_big(&mut self) -> Result<big_uint, RnsError> {
+        let len = self.read_usize()?;
+        Ok(big_uint::from_bytes_le(self.take(len)?))
+    }
+}
+
+fn write_usize(bytes: &mut Vec<u8>, e: usize) {
+    bytes.extend_from_slice(&(e as u64).to_le_bytes());
+}
+
+fn write_fes<F: FieldExt>(bytes: &mut Vec<u8>, fes: &[F]) {
+    for fe in fes.iter() {
+        bytes.extend_from_slice(&fe.to_bytes());
+    }
+}
+
+fn write_big(bytes: &mut Vec<u8>, e: &big_uint) {
+    let e = e.to_bytes_le();
+    write_usize(bytes, e.len());
+    bytes.extend_from_slice(&e);
+}
+
+impl<W: FieldExt, N: FieldExt> Rns<W, N> {
+    /// Encodes precomputed parameters so that they can be loaded without running `construct`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        write_usize(&mut bytes, self.bit_len_limb);
+        write_usize(&mut bytes, self.bit_len_lookup);
+        write_usize(&mut bytes, self.bit_len_prenormalized);
+        write_fes(
+            &mut bytes,
+            &[
+                self.right_shifter_r,
+                self.right_shifter_2r,
+                self.left_shifter_r,
+                self.left_shifter_2r,
+                self.left_shifter_3r,
+                self.wrong_modulus_in_native_modulus,
+            ],
+        );
+        write_fes(&mut bytes, &self.aux.limbs());
+        write_fes(&mut bytes, &self.negative_wrong_modulus);
+        write_fes(&mut bytes, &self.wrong_modulus_decomposed);
+        write_fes(&mut bytes, &self.wrong_modulus_minus_one.limbs());
+        write_big(&mut bytes, &self.wrong_modulus);
+        write_big(&mut bytes, &self.native_modulus);
+        write_big(&mut bytes, &self.limb_max_val);
+        write_big(&mut bytes, &self.most_significant_limb_max_val);
+        write_big(&mut bytes, &self.two_limb_mask);
+        bytes
+    }
+
+    /// Decodes parameters encoded with `to_bytes` and validates them
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RnsError> {
+        let reader = &mut Reader { bytes };
+
+        let bit_len_limb = reader.read_usize()?;
+        let bit_len_lookup = reader.read_usize()?;
+        let bit_len_prenormalized = reader.read_usize()?;
+        let right_shifter_r = reader.read_fe()?;
+        let right_shifter_2r = reader.read_fe()?;
+        let left_shifter_r = reader.read_fe()?;
+        let left_shifter_2r = reader.read_fe()?;
+        let left_shifter_3r = reader.read_fe()?;
+        let wrong_modulus_in_native_modulus = reader.read_fe()?;
+        let aux = Integer::new(reader.read_fes()?.into_iter().map(Limb::new).collect());
+        let negative_wrong_modulus = reader.read_fes()?;
+        let wrong_modulus_decomposed = reader.read_fes()?;
+        let wrong_modulus_minus_one = Integer::new(reader.read_fes()?.into_iter().map(Limb::new).collect());
+        let wrong_modulus = reader.read_big()?;
+        let native_modulus = reader.read_big()?;
+        let limb_max_val = reader.read_big()?;
+        let most_significant_limb_max_val = reader.read_big()?;
+        let two_limb_mask = reader.read_big()?;
+
+        if !reader.bytes.is_empty() {
+            return Err(RnsError::InvalidParameter("trailing bytes"));
+        }
+
+        let negative_wrong_modulus_big = negative_wrong_modulus.iter().map(|e| fe_to_big(*e)).collect();
+
+        let rns = Rns {
+            right_shifter_r,
+            right_shifter_2r,
+            left_shifter_r,
+            left_shifter_2r,
+            left_shifter_3r,
+            aux,
+            negative_wrong_modulus,
+            negative_wrong_modulus_big,
+            wrong_modulus_decomposed,
+            wrong_modulus_minus_one,
+            wrong_modulus_in_native_modulus,
+            bit_len_prenormalized,
+            bit_len_limb,
+            bit_len_lookup,
+            wrong_modulus,
+            limb_max_val,
+            most_significant_limb_max_val,
+            native_modulus,
+            two_limb_mask,
+            _marker_wrong: PhantomData,
+        };
+        rns.validate()?;
+
+        Ok(rns)
+    }
+
+    /// Checks consistency of parameters against the moduli without running `construct`
+    pub fn validate(&self) -> Result<(), RnsError> {
+        let check = |truth: bool, name: &'static str| if truth { Ok(()) } else { Err(RnsError::InvalidParameter(name)) };
+
+        let wrong_modulus = &self.wrong_modulus;
+        let bit_len_limb = self.bit_len_limb;
+        check(bit_len_limb > 0 && bit_len_limb * NUMBER_OF_LIMBS < 512, "bit_len_limb")?;
+        check(*wrong_modulus == field_modulus::<W>(), "wrong_modulus")?;
+        check(self.native_modulus == field_modulus::<N>(), "native_modulus")?;
+        check(self.bit_len_lookup == bit_len_limb / NUMBER_OF_LOOKUP_LIMBS, "bit_len_lookup")?;
+        check(self.bit_len_prenormalized == wrong_modulus.bits() as usize, "bit_len_prenormalized")?;
+
+        let two = N::from_u64(2);
+        let one = N::one();
+        check(self.left_shifter_r == two.pow(&[bit_len_limb as u64, 0, 0, 0]), "left_shifter_r")?;
+        check(self.left_shifter_2r == self.left_shifter_r * self.left_shifter_r, "left_shifter_2r")?;
+        check(self.left_shifter_3r == self.left_shifter_2r * self.left_shifter_r, "left_shifter_3r")?;
+        check(self.right_shifter_r * self.left_shifter_r == one, "right_shifter_r")?;
+        check(self.right_shifter_2r * self.left_shifter_2r == one, "right_shifter_2r")?;
+
+        let crt_modulus = big_uint::one() << (bit_len_limb * NUMBER_OF_LIMBS);
+        let negative_wrong_modulus = crt_modulus.checked_sub(wrong_modulus).ok_or(RnsError::BinaryModulusTooSmall)?;
+        check(compose_fe(self.wrong_modulus_decomposed.clone(), bit_len_limb) == *wrong_modulus, "wrong_modulus_decomposed")?;
+        check(
+            compose_fe(self.negative_wrong_modulus.clone(), bit_len_limb) == negative_wrong_modulus,
+            "negative_wrong_modulus",
+        )?;
+        check(self.value(&self.wrong_modulus_minus_one) == wrong_modulus.clone() - 1usize, "wrong_modulus_minus_one")?;
+        check(
+            self.wrong_modulus_in_native_modulus == big_to_fe(wrong_modulus % &self.native_modulus),
+            "wrong_modulus_in_native_modulus",
+        )?;
+        check((self.value(&self.aux) % wrong_modulus).is_zero(), "aux")?;
+
+        let most_significant_limb_bit_len = self
+            .bit_len_prenormalized
+            .checked_sub(bit_len_limb * (NUMBER_OF_LIMBS - 1))
+            .ok_or(RnsError::MostSignificantLimbEmpty)?;
+        check(self.limb_max_val == (big_uint::one() << bit_len_limb) - 1usize, "limb_max_val")?;
+        check(
+            self.most_significant_limb_max_val == (big_uint::one() << most_significant_limb_bit_len) - 1usize,
+            "most_significant_limb_max_val",
+        )?;
+        check(self.two_limb_mask == (big_uint::one() << (bit_len_limb * 2)) - 1usize, "two_limb_mask")?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
 pub struct Limb<F: FieldExt> {
     _value: F,
 }
@@ -418,6 +1449,18 @@ impl<F: FieldExt> Common<F> for Limb<F> {
     }
 }
 
+impl<F: FieldExt> fmt::Display for Limb<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl<F: FieldExt> fmt::LowerHex for Limb<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.value(), f)
+    }
+}
+
 impl<F: FieldExt> Default for Limb<F> {
     fn default() -> Self {
         Limb { _value: F::zero() }
@@ -455,6 +1498,8 @@ impl<F: FieldExt> Limb<F> {
 #[derive(Clone, Default)]
 pub struct Integer<F: FieldExt> {
     limbs: Vec<Limb<F>>,
+    // limb width of the rns that built the integer, unknown for integers from raw limbs
+    bit_len_limb: Option<usize>,
 }
 
 impl<F: FieldExt> fmt::Debug for Integer<F> {
@@ -471,23 +1516,90 @@ impl<F: FieldExt> fmt::Debug for Integer<F> {
     }
 }
 
+/// Integers are equal if they compose to the same value regardless of limb representation
+impl<F: FieldExt> PartialEq for Integer<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+}
+
+impl<F: FieldExt> Eq for Integer<F> {}
+
+impl<F: FieldExt> std::hash::Hash for Integer<F> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value().hash(state)
+    }
+}
+
+impl<F: FieldExt> fmt::Display for Integer<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl<F: FieldExt> fmt::LowerHex for Integer<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.value(), f)
+    }
+}
+
 impl<N: FieldExt> Common<N> for Integer<N> {
     fn value(&self) -> big_uint {
         let limb_values = self.limbs.iter().map(|limb| limb.value()).collect();
-        compose(limb_values, BIT_LEN_LIMB)
+        compose(limb_values, self.bit_len_limb.unwrap_or(BIT_LEN_LIMB))
     }
 }
 
 impl<F: FieldExt> Integer<F> {
     pub fn new(limbs: Vec<Limb<F>>) -> Self {
         assert!(limbs.len() == NUMBER_OF_LIMBS);
-        Self { limbs }
+        Self { limbs, bit_len_limb: None }
+    }
+
+    pub fn new_with_bit_len_limb(limbs: Vec<Limb<F>>, bit_len_limb: usize) -> Self {
+        assert!(limbs.len() == NUMBER_OF_LIMBS);
+        Self {
+            limbs,
+            bit_len_limb: Some(bit_len_limb),
+        }
     }
 
     pub fn from_big(e: big_uint, number_of_limbs: usize, bit_len: usize) -> Self {
+        // rns operations index limbs up to `NUMBER_OF_LIMBS` so fail here rather than deep inside them
+        assert!(
+            number_of_limbs == NUMBER_OF_LIMBS,
+            "integer must be decomposed into {} limbs, got {}",
+            NUMBER_OF_LIMBS,
+            number_of_limbs
+        );
         let limbs = decompose::<F>(e, number_of_limbs, bit_len);
         let limbs = limbs.iter().map(|e| Limb::<F>::new(*e)).collect();
-        Self { limbs }
+        Self {
+            limbs,
+            bit_len_limb: Some(bit_len),
+        }
+    }
+
+    pub fn from_bytes_le(e: &[u8], number_of_limbs: usize, bit_len: usize) -> Self {
+        Self::from_big(big_uint::from_bytes_le(e), number_of_limbs, bit_len)
+    }
+
+    pub fn from_bytes_be(e: &[u8], number_of_limbs: usize, bit_len: usize) -> Self {
+        let mut e = e.to_vec();
+        e.reverse();
+        Self::from_bytes_le(&e, number_of_limbs, bit_len)
+    }
+
+    /// Minimal little endian encoding of the composed value
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        self.value().to_bytes_le()
+    }
+
+    /// Minimal big endian encoding of the composed value
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
     }
 
     pub fn limbs(&self) -> Vec<F> {
@@ -499,7 +1611,12 @@ impl<F: FieldExt> Integer<F> {
     }
 
     pub fn limb(&self, idx: usize) -> Limb<F> {
-        self.limbs[idx].clone()
+        self.limbs[idx]
+    }
+
+    /// Returns true if limbs compose to zero, congruence to zero is checked with `Rns::is_zero_mod`
+    pub fn is_zero(&self) -> bool {
+        self.value().is_zero()
     }
 
     pub fn scale(&mut self, k: F) {
@@ -509,10 +1626,43 @@ impl<F: FieldExt> Integer<F> {
     }
 }
 
+/// Integer holding a secret value which is cleared when dropped
+#[cfg(feature = "zeroize")]
+pub struct SecretInteger<F: FieldExt>(Integer<F>);
+
+#[cfg(feature = "zeroize")]
+impl<F: FieldExt> SecretInteger<F> {
+    pub fn new(integer: Integer<F>) -> Self {
+        SecretInteger(integer)
+    }
+
+    pub fn integer(&self) -> &Integer<F> {
+        &self.0
+    }
+}
+
+// Default limb is the zero element
+#[cfg(feature = "zeroize")]
+impl<F: FieldExt> zeroize::DefaultIsZeroes for Limb<F> {}
+
+#[cfg(feature = "zeroize")]
+impl<F: FieldExt> zeroize::Zeroize for SecretInteger<F> {
+    fn zeroize(&mut self) {
+        self.0.limbs.iter_mut().for_each(zeroize::Zeroize::zeroize);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: FieldExt> Drop for SecretInteger<F> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use super::{big_to_fe, fe_to_big, modulus, Rns};
+    use super::{big_to_fe, fe_to_big, field_modulus, Rns};
     use crate::rns::Common;
     use crate::rns::Integer;
     use crate::NUMBER_OF_LIMBS;
@@ -535,6 +1685,33 @@ mod tests {
         assert_eq!(decomposed.value(), el.clone());
     }
 
+    #[test]
+    fn test_decompose_ct() {
+        use super::{decompose_ct, decompose_fe};
+
+        for (number_of_limbs, bit_len) in vec![(4usize, 64usize), (4, 68), (3, 85), (5, 51), (2, 64)].into_iter() {
+            for _ in 0..20 {
+                let e = Fp::rand();
+                assert_eq!(decompose_ct::<Fp>(e, number_of_limbs, bit_len), decompose_fe::<Fp>(e, number_of_limbs, bit_len));
+            }
+            let e: Fp = big_to_fe(field_modulus::<Fp>() - 1usize);
+            assert_eq!(decompose_ct::<Fp>(e, number_of_limbs, bit_len), decompose_fe::<Fp>(e, number_of_limbs, bit_len));
+        }
+    }
+
+    #[test]
+    fn test_field_modulus_accessors() {
+        use halo2::pasta::Fp as Wrong;
+        use num_traits::Num;
+        use halo2::pasta::Fq as Native;
+
+        let rns = Rns::<Wrong, Native>::construct(64);
+        let parse = |hex: &str| big_uint::from_str_radix(&hex[2..], 16).unwrap();
+        assert_eq!(*rns.wrong_field_modulus(), parse(Wrong::MODULUS));
+        assert_eq!(*rns.native_field_modulus(), parse(Native::MODULUS));
+        assert_eq!(field_modulus::<Wrong>(), parse(Wrong::MODULUS));
+    }
+
     #[test]
     fn test_rns_constants() {
         use halo2::pasta::Fp as Wrong;
@@ -545,7 +1722,7 @@ mod tests {
         let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
 
         let wrong_modulus = rns.wrong_modulus.clone();
-        let native_modulus = modulus::<Native>();
+        let native_modulus = field_modulus::<Native>();
 
         // shifters
 
@@ -664,25 +1841,933 @@ mod tests {
         }
     }
 
-    // #[test]
-    // fn test_comparison() {
-    //     use halo2::pasta::Fp as Wrong;
-    //     use halo2::pasta::Fq as Native;
-    //     let bit_len_limb = 64;
+    #[test]
+    fn test_rns_serialization() {
+        use super::RnsError;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        assert_eq!(rns.validate(), Ok(()));
+
+        let bytes = rns.to_bytes();
+        let rns_loaded = Rns::<Wrong, Native>::from_bytes(&bytes).unwrap();
+        assert_eq!(rns_loaded.to_bytes(), bytes);
+        assert_eq!(rns_loaded.wrong_modulus, rns.wrong_modulus);
+        assert_eq!(rns_loaded.aux.value(), rns.aux.value());
+
+        // truncated
+        let result = Rns::<Wrong, Native>::from_bytes(&bytes[..bytes.len() - 1]);
+        assert_eq!(result.map(|_| ()), Err(RnsError::UnexpectedEnd));
+
+        // corrupted shifter
+        let mut corrupted = bytes.clone();
+        corrupted[3 * 8 + 32] ^= 1;
+        let result = Rns::<Wrong, Native>::from_bytes(&corrupted);
+        assert!(result.is_err());
+
+        // corrupted last byte of two limb mask
+        let mut corrupted = bytes.clone();
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 1;
+        let result = Rns::<Wrong, Native>::from_bytes(&corrupted);
+        assert_eq!(result.map(|_| ()), Err(RnsError::InvalidParameter("two_limb_mask")));
+
+        // parameters of another field
+        let result = Rns::<Native, Native>::from_bytes(&bytes);
+        assert_eq!(result.map(|_| ()), Err(RnsError::InvalidParameter("wrong_modulus")));
+
+        // consistent shifters but limbs too narrow to cover the wrong modulus
+        let mut narrow = rns.clone();
+        let two = Native::from_u64(2);
+        narrow.bit_len_limb = 16;
+        narrow.bit_len_lookup = 16 / crate::NUMBER_OF_LOOKUP_LIMBS;
+        narrow.left_shifter_r = two.pow(&[16, 0, 0, 0]);
+        narrow.left_shifter_2r = two.pow(&[32, 0, 0, 0]);
+        narrow.left_shifter_3r = two.pow(&[48, 0, 0, 0]);
+        narrow.right_shifter_r = narrow.left_shifter_r.invert().unwrap();
+        narrow.right_shifter_2r = narrow.left_shifter_2r.invert().unwrap();
+        assert_eq!(narrow.validate(), Err(RnsError::BinaryModulusTooSmall));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "limb product overflow at t_0")]
+    fn test_mul_overflow_detection() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // product of limbs alone is wider than the native field
+        let el_0 = &rns.rand_with_limb_bit_size(2 * bit_len_limb);
+        let el_1 = &rns.rand_with_limb_bit_size(2 * bit_len_limb);
+        rns.mul(el_0, el_1);
+    }
+
+    #[test]
+    fn test_reduction_assignments() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        use std::collections::HashMap;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let s = rns.left_shifter_r;
+
+        let el_0 = &rns.rand_prenormalized();
+        let el_1 = &rns.rand_prenormalized();
+        let assignments: HashMap<&str, Native> = rns.mul(el_0, el_1).to_assignments().into_iter().collect();
+
+        // t_k = sum a_i * b_j + p'_i * q_j where i + j = k
+        for k in 0..NUMBER_OF_LIMBS {
+            let mut t = Native::from_u64(0);
+            for i in 0..=k {
+                let j = k - i;
+                let q = assignments[format!("quotient_{}", j).as_str()];
+                t = t + el_0.limb_value(i) * el_1.limb_value(j) + rns.negative_wrong_modulus[i] * q;
+            }
+            assert_eq!(t, assignments[format!("t_{}", k).as_str()]);
+        }
+
+        // residues are consistent with intermediate values and the result
+        let (t, r) = (|k: usize| assignments[format!("t_{}", k).as_str()], |k: usize| assignments[format!("result_{}", k).as_str()]);
+        let (u_0, u_1, v_0, v_1) = (assignments["u_0"], assignments["u_1"], assignments["v_0"], assignments["v_1"]);
+        assert_eq!(u_0, t(0) + s * t(1) - r(0) - s * r(1));
+        assert_eq!(u_1, t(2) + s * t(3) - r(2) - s * r(3));
+        assert_eq!(v_0 * rns.left_shifter_2r, u_0);
+        assert_eq!(v_1 * rns.left_shifter_2r, u_1 + v_0);
+
+        let result = rns.new_from_limbs((0..NUMBER_OF_LIMBS).map(r).collect());
+        assert_eq!(result.value(), (el_0.value() * el_1.value()) % rns.wrong_modulus.clone());
+
+        // reduction has a single quotient value
+        let el = rns.rand_with_limb_bit_size(bit_len_limb + 10);
+        let assignments: HashMap<&str, Native> = rns.reduce(&el).to_assignments().into_iter().collect();
+        let q = assignments["quotient"];
+        for k in 0..NUMBER_OF_LIMBS {
+            let t = el.limb_value(k) + rns.negative_wrong_modulus[k] * q;
+            assert_eq!(t, assignments[format!("t_{}", k).as_str()]);
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_secret_integer_zeroize() {
+        use super::SecretInteger;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        use zeroize::Zeroize;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let mut secret = SecretInteger::new(rns.rand_normalized());
+        let limbs = secret.integer().limbs.as_ptr();
+
+        // drop delegates to zeroize so clearing is observed without reading freed memory
+        secret.zeroize();
+        assert_eq!(secret.integer().limbs.as_ptr(), limbs);
+        assert_eq!(secret.integer().limbs(), vec![Native::from_u64(0); NUMBER_OF_LIMBS]);
+    }
 
-    //     let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+    #[test]
+    fn test_max_additions_before_reduce() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
 
-    //     let wrong_modulus = rns.wrong_modulus_decomposed.clone();
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let n = rns.max_additions_before_reduce();
+        let max_unreduced_limb = rns.max_unreduced_limb();
+
+        // a chain of n additions sums n + 1 reduced integers
+        let max_val = |additions: usize| rns.max_reduced_limb() * (additions + 1);
+        assert!(max_val(n) <= max_unreduced_limb);
+        assert!(max_val(n + 1) > max_unreduced_limb);
+
+        // integer that has all limbs at the bound is still reducible
+        let limb: Native = big_to_fe(max_val(n));
+        let a = rns.new_from_limbs(vec![limb; NUMBER_OF_LIMBS]);
+        let reduction_context = rns.reduce(&a);
+        assert_eq!(reduction_context.result.value(), a.value() % rns.wrong_modulus.clone());
+    }
 
-    //     let a_0 = wrong_modulus[0];
-    //     let a_1 = wrong_modulus[1];
-    //     let a_2 = wrong_modulus[2];
-    //     let a_3 = wrong_modulus[3];
+    #[test]
+    fn test_make_aux() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
 
-    //     let a = &rns.new_from_limbs(vec![a_0, a_1, a_2, a_3]);
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let base_aux: Vec<big_uint> = rns.aux.limbs().into_iter().map(fe_to_big).collect();
+        let one = big_uint::one();
+
+        // max values that base aux already covers need no shift
+        let aux = rns.make_aux(&[one.clone(), one.clone(), one.clone(), one.clone()]);
+        assert_eq!(aux.value(), rns.aux.value());
+
+        // a single wide limb decides the shift of all limbs
+        let skewed = [one.clone(), one.clone(), base_aux[2].clone() << 40usize, one.clone()];
+        let aux = rns.make_aux(&skewed);
+        assert_eq!(aux.value(), rns.aux.value() << 40usize);
+        assert_eq!(aux.value() % rns.wrong_modulus.clone(), big_uint::zero());
+
+        let skewed = [(base_aux[0].clone() << 20usize) + 1usize, one.clone(), one.clone(), base_aux[3].clone() << 7usize];
+        let aux = rns.make_aux(&skewed);
+        assert_eq!(aux.value(), rns.aux.value() << 21usize);
+        for (aux_limb, max_val) in aux.limbs().into_iter().zip(skewed.iter()) {
+            assert!(fe_to_big(aux_limb) >= *max_val);
+        }
+    }
 
-    //     let comparison_result = rns.compare_to_modulus(a);
-    //     println!("{:?}", comparison_result.borrow);
-    //     println!("{:?}", comparison_result.result);
-    // }
+    #[test]
+    fn test_common_bits() {
+        use super::Limb;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        assert_eq!(Limb::<Native>::from("ff").bits(), 8);
+        assert_eq!(Limb::<Native>::from("0").bits(), 0);
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let a = rns.new_from_big(big_uint::one() << 200usize);
+        assert_eq!(a.bits(), 201);
+    }
+
+    #[test]
+    fn test_new_from_big_unreduced() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let shift = bit_len_limb * (NUMBER_OF_LIMBS - 1);
+        let lower = rand::thread_rng().gen_biguint(shift as u64);
+        let e = (rns.max_unreduced_limb() << shift) + lower;
+
+        // dense decomposition drops the bits that don't fit
+        assert_ne!(rns.new_from_big(e.clone()).value(), e);
+
+        let a = rns.new_from_big_unreduced(e.clone());
+        assert_eq!(a.value(), e);
+        let reduction_context = rns.reduce(&a);
+        assert_eq!(reduction_context.result.value(), e % rns.wrong_modulus.clone());
+    }
+
+    #[test]
+    fn test_testing_ranges() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let max_remainder = rns.max_in_remainder_range().value();
+        let max_operand = rns.max_in_operand_range().value();
+        assert_eq!(max_remainder, rns.wrong_modulus.clone() - 1usize);
+        assert_eq!(max_operand.bits() as usize, rns.bit_len_prenormalized);
+
+        for _ in 0..100 {
+            assert!(rns.rand_in_remainder_range().value() <= max_remainder);
+            assert!(rns.rand_in_operand_range().value() <= max_operand);
+            let a = rns.rand_in_unreduced_range();
+            for limb in a.limbs().into_iter() {
+                assert!(fe_to_big(limb) <= rns.max_unreduced_limb());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "integer must be decomposed into 4 limbs, got 3")]
+    fn test_integer_number_of_limbs() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let a = Integer::<Native>::from_big(big_uint::one(), 3, bit_len_limb);
+        rns.mul(&a, &a);
+    }
+
+    #[test]
+    #[should_panic(expected = "integer with 60 bit limbs is used with rns of 64 bit limbs")]
+    fn test_mul_limb_width_mismatch() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let a = rns.rand_normalized();
+        let b = Integer::<Native>::from_big(big_uint::one(), NUMBER_OF_LIMBS, 60);
+        rns.mul(&a, &b);
+    }
+
+    #[test]
+    fn test_construct_auto() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        let rns = Rns::<Wrong, Native>::construct_auto().unwrap();
+        assert!(Rns::<Wrong, Native>::try_construct(rns.bit_len_limb).is_ok());
+
+        let a = Wrong::rand();
+        let b = Wrong::rand();
+        let integer_a = rns.new_in_crt(a);
+        let integer_b = rns.new_in_crt(b);
+        assert_eq!(rns.to_wrong_field(&rns.mul(&integer_a, &integer_b).result), a * b);
+        assert_eq!(rns.to_wrong_field(&rns.reduce(&integer_a).result), a);
+    }
+
+    #[test]
+    fn test_try_construct() {
+        use super::RnsError;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+
+        assert!(Rns::<Wrong, Native>::try_construct(64).is_ok());
+        assert_eq!(Rns::<Wrong, Native>::try_construct(62).map(|_| ()), Err(RnsError::LimbNotLookupAligned));
+        assert_eq!(Rns::<Wrong, Native>::try_construct(60).map(|_| ()), Err(RnsError::BinaryModulusTooSmall));
+        assert_eq!(Rns::<Wrong, Native>::try_construct(88).map(|_| ()), Err(RnsError::MostSignificantLimbEmpty));
+    }
+
+    const BN254_SCALAR_MODULUS: &str = "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+
+    #[test]
+    fn test_check_moduli_25519() {
+        use super::{check_moduli, RnsError};
+        use num_traits::Num;
+
+        let p_25519 = (big_uint::one() << 255usize) - 19usize;
+        let bn254_scalar = big_uint::from_str_radix(BN254_SCALAR_MODULUS, 10).unwrap();
+
+        assert_eq!(check_moduli(&p_25519, &field_modulus::<Fq>(), 64), Ok(()));
+
+        // 2^256 * r is just below (2^255)^2 so operands of full wrong field width cannot be multiplied
+        assert_eq!(check_moduli(&p_25519, &bn254_scalar, 64), Err(RnsError::CrtModulusTooSmall));
+    }
+
+    #[test]
+    fn test_rns_params_mul_25519() {
+        use super::{RnsField, RnsParams};
+        use num_traits::Num;
+
+        // 2^255 - 19 has no `FieldExt` implementation here so only its modulus is provided
+        #[derive(Clone, Copy)]
+        struct F25519([u8; 32]);
+
+        impl RnsField for F25519 {
+            type Bytes = [u8; 32];
+
+            fn modulus() -> big_uint {
+                (big_uint::one() << 255usize) - 19usize
+            }
+
+            fn bytes(&self) -> [u8; 32] {
+                self.0
+            }
+
+            fn from_str(s: &str) -> Option<Self> {
+                let e = big_uint::from_str_radix(s, 10).ok().filter(|e| *e < Self::modulus())?;
+                let mut bytes = [0u8; 32];
+                let le = e.to_bytes_le();
+                bytes[..le.len()].copy_from_slice(&le);
+                Some(F25519(bytes))
+            }
+        }
+
+        let mut rng = XorShiftRng::from_seed([0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5]);
+        let params = RnsParams::construct::<F25519, Fq>(64).unwrap();
+        let p = F25519::modulus();
+
+        let max = &p - 1usize;
+        assert_eq!(params.mul(&max, &max).unwrap(), (&max * &max) % &p);
+
+        for _ in 0..100 {
+            let a = rng.gen_biguint_below(&p);
+            let b = rng.gen_biguint_below(&p);
+            assert_eq!(params.mul(&a, &b).unwrap(), (&a * &b) % &p);
+        }
+    }
+
+    // Only the moduli are checked, instantiating EccChip for this pair is blocked until halo2 provides bn256 and secp256k1 types
+    #[test]
+    fn test_check_moduli_secp256k1_over_bn254() {
+        use super::{check_moduli, RnsError};
+        use num_traits::Num;
+
+        let secp256k1_base = big_uint::from_str_radix("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", 16).unwrap();
+        let bn254_scalar = big_uint::from_str_radix(BN254_SCALAR_MODULUS, 10).unwrap();
+
+        assert_eq!(check_moduli(&secp256k1_base, &bn254_scalar, 68), Ok(()));
+        assert_eq!(check_moduli(&secp256k1_base, &bn254_scalar, 64), Err(RnsError::CrtModulusTooSmall));
+    }
+
+    #[test]
+    fn test_reduce_zero() {
+        use super::Quotient;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let zero = rns.new_from_big(big_uint::zero());
+        let reduction_context = rns.reduce(&zero);
+
+        match reduction_context.quotient {
+            Quotient::Short(quotient) => assert_eq!(quotient, Native::from_u64(0)),
+            _ => panic!("short quotient expected"),
+        }
+        assert!(reduction_context.result.value().is_zero());
+        assert!(reduction_context.t.iter().all(|t| *t == Native::from_u64(0)));
+        let residues = vec![reduction_context.u_0, reduction_context.u_1, reduction_context.v_0, reduction_context.v_1];
+        assert!(residues.iter().all(|e| *e == Native::from_u64(0)));
+    }
+
+    #[test]
+    fn test_reduce_large() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let mut rng = XorShiftRng::from_seed([0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5]);
+
+        // limbs as wide as the native field allows, far above what a single limb quotient reduces
+        let limbs: Vec<Native> = (0..NUMBER_OF_LIMBS).map(|_| big_to_fe(rng.gen_biguint(250))).collect();
+        let integer = rns.new_from_limbs(limbs);
+        let value = integer.value();
+        assert!(value.clone() / rns.wrong_modulus.clone() >= big_uint::one() << bit_len_limb);
+
+        let contexts = rns.reduce_large(&integer);
+        assert_eq!(contexts.len(), 3);
+        let result = contexts.last().unwrap().result.value();
+        assert_eq!(result, value % rns.wrong_modulus.clone());
+
+        // value that is 10 limbs wide
+        let limbs: Vec<Native> = (0..10).map(|_| big_to_fe(rng.gen_biguint(bit_len_limb as u64))).collect();
+        let integer = rns.new_from_limbs(limbs);
+        let value = integer.value();
+        assert!(value.bits() > (bit_len_limb * 9) as u64);
+
+        let contexts = rns.reduce_large(&integer);
+        assert_eq!(contexts.len(), 5);
+        let result = contexts.last().unwrap().result.value();
+        assert_eq!(result, value % rns.wrong_modulus.clone());
+
+        // small inputs take a single step
+        let integer = rns.rand_normalized();
+        let contexts = rns.reduce_large(&integer);
+        assert_eq!(contexts.len(), 1);
+        assert_eq!(contexts[0].result.value(), integer.value() % rns.wrong_modulus.clone());
+    }
+
+    #[test]
+    fn test_big_to_fe_reduction() {
+        let modulus = field_modulus::<Fp>();
+        assert_eq!(big_to_fe::<Fp>(modulus.clone()), Fp::from_u64(0));
+        assert_eq!(big_to_fe::<Fp>(modulus.clone() - 1usize), -Fp::from_u64(1));
+        assert_eq!(big_to_fe::<Fp>(modulus + 5usize), Fp::from_u64(5));
+    }
+
+    #[test]
+    fn test_new_from_limb_structs() {
+        use super::Limb;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let limbs = rns.rand_normalized().limbs();
+
+        let a = rns.new_from_limbs(limbs.clone());
+        let b = rns.new_from_limb_structs(limbs.into_iter().map(Limb::new).collect());
+        assert_eq!(a.limbs(), b.limbs());
+        assert_eq!(a.value(), b.value());
+    }
+
+    #[test]
+    fn test_new_from_limbs_checked() {
+        use super::LimbError;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        for _ in 0..100 {
+            let limbs = rns.rand_normalized().limbs();
+            assert_eq!(rns.new_from_limbs_checked(limbs.clone()).unwrap(), rns.new_from_limbs(limbs));
+        }
+
+        let mut limbs = rns.rand_normalized().limbs();
+        limbs[1] = big_to_fe(big_uint::one() << bit_len_limb);
+        assert_eq!(rns.new_from_limbs_checked(limbs.clone()), Err(LimbError::LimbTooWide(1)));
+
+        limbs[1] = Native::zero();
+        limbs[NUMBER_OF_LIMBS - 1] = big_to_fe(rns.most_significant_limb_max_val.clone() + 1usize);
+        assert_eq!(rns.new_from_limbs_checked(limbs), Err(LimbError::LimbTooWide(NUMBER_OF_LIMBS - 1)));
+
+        assert_eq!(rns.new_from_limbs_checked(vec![Native::zero(); 3]), Err(LimbError::WrongNumberOfLimbs(3)));
+    }
+
+    #[test]
+    fn test_comparison() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let a = &rns.new_from_big(rns.wrong_modulus.clone());
+        let comparison_result = rns.compare_to_modulus(a);
+        assert!(comparison_result.top_borrow);
+
+        let a = &rns.new_from_big(rns.wrong_modulus.clone() - 1usize);
+        let comparison_result = rns.compare_to_modulus(a);
+        assert!(!comparison_result.top_borrow);
+        assert!(comparison_result.result.value().is_zero());
+    }
+
+    #[test]
+    fn test_sub_with_borrow() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+        let binary_modulus = big_uint::one() << (bit_len_limb * NUMBER_OF_LIMBS);
+
+        for _ in 0..100 {
+            let a = &rns.rand_normalized();
+            let b = &rns.rand_normalized();
+            let (a, b) = if a.value() > b.value() { (a, b) } else { (b, a) };
+
+            let (result, _, top_borrow) = rns.sub_with_borrow(a, b);
+            assert!(!top_borrow);
+            assert_eq!(result.value(), a.value() - b.value());
+
+            let (result, borrow, top_borrow) = rns.sub_with_borrow(a, a);
+            assert!(!top_borrow);
+            assert_eq!(borrow, [false; NUMBER_OF_LIMBS]);
+            assert!(result.value().is_zero());
+
+            let (result, _, top_borrow) = rns.sub_with_borrow(b, a);
+            assert!(top_borrow);
+            assert_eq!(result.value(), binary_modulus.clone() + b.value() - a.value());
+        }
+    }
+
+    #[test]
+    fn test_is_quadratic_residue() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        assert!(rns.is_quadratic_residue(&rns.new_from_big(big_uint::zero())));
+        assert!(rns.is_quadratic_residue(&rns.new_from_big(big_uint::one())));
+        assert!(rns.is_quadratic_residue(&rns.new_from_big(big_uint::from(4u64))));
+        assert!(!rns.is_quadratic_residue(&rns.non_residue()));
+
+        for _ in 0..100 {
+            let root = Wrong::rand();
+            let square = rns.new_from_big(fe_to_big(root * root));
+            assert!(rns.is_quadratic_residue(&square));
+
+            // non residue times a nonzero square is a non residue
+            if root != Wrong::from_u64(0) {
+                let non_square = rns.new_from_big(fe_to_big(root * root * Wrong::ROOT_OF_UNITY));
+                assert!(!rns.is_quadratic_residue(&non_square));
+            }
+        }
+    }
+
+    #[test]
+    fn test_legendre_symbol_and_sqrt() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = &Rns::<Wrong, Native>::construct(bit_len_limb);
+        let wrong_modulus = rns.wrong_modulus.clone();
+
+        assert_eq!(rns.legendre_symbol(&rns.new_from_big(big_uint::zero())), 0);
+        assert_eq!(rns.legendre_symbol(&rns.new_from_big(wrong_modulus.clone())), 0);
+        assert_eq!(rns.legendre_symbol(&rns.new_from_big(big_uint::from(4u64))), 1);
+        assert_eq!(rns.legendre_symbol(&rns.non_residue()), -1);
+        assert!(rns.sqrt(&rns.non_residue()).is_none());
+        assert_eq!(rns.sqrt(&rns.new_from_big(big_uint::zero())).unwrap().value(), big_uint::zero());
+
+        for _ in 0..100 {
+            let a = rns.rand_normalized();
+            let symbol = rns.legendre_symbol(&a);
+            match rns.sqrt(&a) {
+                Some(root) => {
+                    assert!(symbol >= 0);
+                    assert_eq!((root.value() * root.value()) % &wrong_modulus, a.value() % &wrong_modulus);
+                }
+                None => assert_eq!(symbol, -1),
+            }
+        }
+    }
+
+    #[test]
+    fn test_negative_wrong_modulus_cache() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let decoded = Rns::<Wrong, Native>::from_bytes(&rns.to_bytes()).unwrap();
+        for rns in vec![rns, decoded].iter() {
+            let expected: Vec<big_uint> = rns.negative_wrong_modulus.iter().map(|e| fe_to_big(*e)).collect();
+            assert_eq!(rns.negative_wrong_modulus_big, expected);
+        }
+    }
+
+    #[test]
+    fn test_residues_independent_of_sanity_check() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        let s = rns.left_shifter_r;
+
+        for _ in 0..100 {
+            let a = rns.rand_normalized();
+            let b = rns.rand_normalized();
+            let reduction_context = rns.mul(&a, &b);
+
+            // residues recomputed without going through the checked path
+            let (t, r) = (&reduction_context.t, &reduction_context.result);
+            let u_0 = t[0] + s * t[1] - r.limb_value(0) - s * r.limb_value(1);
+            let u_1 = t[2] + s * t[3] - r.limb_value(2) - s * r.limb_value(3);
+            let v_0 = u_0 * rns.right_shifter_2r;
+            let v_1 = (u_1 + v_0) * rns.right_shifter_2r;
+
+            assert_eq!(reduction_context.v_0, v_0);
+            assert_eq!(reduction_context.v_1, v_1);
+            assert_eq!(rns.residues(t.clone(), r.clone()), (u_0, u_1, v_0, v_1));
+        }
+    }
+
+    #[test]
+    fn test_try_mul() {
+        use super::{Quotient, ResidueError};
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // operands at the largest allowed bound
+        let max_operand = rns.new_from_big(rns.max_operand());
+        let reduction_context = rns.try_mul(&max_operand, &max_operand).unwrap();
+        assert_eq!(reduction_context.to_assignments(), rns.mul(&max_operand, &max_operand).to_assignments());
+
+        let a = rns.rand_normalized();
+        let b = rns.rand_normalized();
+        let reduction_context = rns.try_mul(&a, &b).unwrap();
+
+        let mut t = reduction_context.t.clone();
+        t[0] = t[0] + Native::one();
+        let quotient = Quotient::Long(rns.new_from_big(big_uint::zero()));
+        let result = rns.try_reduction_context(t, reduction_context.result.clone(), quotient.clone());
+        assert_eq!(result.unwrap_err(), ResidueError::U0);
+
+        let mut t = reduction_context.t.clone();
+        t[2] = t[2] + Native::one();
+        let result = rns.try_reduction_context(t, reduction_context.result, quotient);
+        assert_eq!(result.unwrap_err(), ResidueError::U1);
+    }
+
+    #[test]
+    fn test_mul_constant() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let constants = vec![big_uint::from(3u64), fe_to_big(Wrong::rand()), rns.wrong_modulus.clone() + 3usize];
+        for constant in constants.iter() {
+            let a = rns.rand_normalized();
+            let expected = rns.mul(&a, &rns.new_from_big(constant % &rns.wrong_modulus));
+            let reduction_context = rns.mul_constant(&a, constant);
+            assert_eq!(reduction_context.to_assignments(), expected.to_assignments());
+        }
+    }
+
+    #[test]
+    fn test_mul_and_check() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        for _ in 0..100 {
+            let a = rns.rand_normalized();
+            let b = rns.rand_normalized();
+            let c = rns.mul(&a, &b).result;
+            assert!(rns.mul_and_check(&a, &b, &c));
+
+            // unreduced expected value is congruent as well
+            let c_unreduced = rns.new_from_big(rns.value(&c) + rns.wrong_modulus.clone());
+            assert!(rns.mul_and_check(&a, &b, &c_unreduced));
+
+            let c_wrong = rns.new_from_big(rns.value(&c) + 1usize);
+            assert!(!rns.mul_and_check(&a, &b, &c_wrong));
+        }
+    }
+
+    #[test]
+    fn test_mul_prepared() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let k = rns.rand_normalized();
+        let multiplier = rns.prepare(&k);
+        assert_eq!(multiplier.integer(), &k);
+        for _ in 0..10 {
+            let a = rns.rand_normalized();
+            let expected = rns.mul(&a, &k);
+            let reduction_context = rns.mul_prepared(&a, &multiplier);
+            assert_eq!(reduction_context.to_assignments(), expected.to_assignments());
+        }
+    }
+
+    #[test]
+    fn test_zero_detection() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let zero = rns.new_from_big(big_uint::zero());
+        assert!(zero.is_zero());
+        assert!(rns.is_zero_mod(&zero));
+
+        let wrong_modulus = rns.new_from_big(rns.wrong_modulus.clone());
+        assert!(!wrong_modulus.is_zero());
+        assert!(rns.is_zero_mod(&wrong_modulus));
+
+        let a = rns.new_from_big(fe_to_big(Wrong::rand()) + 1usize);
+        assert!(!a.is_zero());
+        assert!(!rns.is_zero_mod(&a));
+    }
+
+    #[test]
+    fn test_wrong_modulus_integers() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        assert_eq!(rns.wrong_modulus_integer().value(), rns.wrong_modulus);
+        let t = big_uint::one() << (bit_len_limb * NUMBER_OF_LIMBS);
+        assert_eq!(rns.neg_wrong_modulus_integer().value(), t - rns.wrong_modulus.clone());
+    }
+
+    #[test]
+    fn test_to_wrong_field() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        for _ in 0..100 {
+            let a = Wrong::rand();
+            assert_eq!(rns.to_wrong_field(&rns.new_in_crt(a)), a);
+        }
+
+        // unreduced values are taken modulo the wrong modulus
+        let a = Wrong::rand();
+        let unreduced = rns.new_from_big(fe_to_big(a) + rns.wrong_modulus.clone());
+        assert_eq!(rns.to_wrong_field(&unreduced), a);
+    }
+
+    #[test]
+    fn test_integer_bytes() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        for _ in 0..100 {
+            let integer = rns.rand_normalized();
+            let bytes_le = integer.to_bytes_le();
+            let mut bytes_be = integer.to_bytes_be();
+            assert_eq!(Integer::<Native>::from_bytes_le(&bytes_le, NUMBER_OF_LIMBS, bit_len_limb), integer);
+            assert_eq!(Integer::<Native>::from_bytes_be(&bytes_be, NUMBER_OF_LIMBS, bit_len_limb), integer);
+
+            bytes_be.reverse();
+            assert_eq!(bytes_be, bytes_le);
+        }
+
+        // leading zeros of big endian input don't change the value
+        let bytes: Vec<u8> = (1..=32).collect();
+        let mut reversed = bytes.clone();
+        reversed.reverse();
+        let mut padded = vec![0u8; 8];
+        padded.extend(bytes.iter());
+        let expected = Integer::<Native>::from_bytes_le(&reversed, NUMBER_OF_LIMBS, bit_len_limb);
+        assert_eq!(Integer::<Native>::from_bytes_be(&bytes, NUMBER_OF_LIMBS, bit_len_limb), expected);
+        assert_eq!(Integer::<Native>::from_bytes_be(&padded, NUMBER_OF_LIMBS, bit_len_limb), expected);
+    }
+
+    #[test]
+    fn test_new_from_str() {
+        use halo2::pasta::Fp as Wrong;
+        use num_traits::Num;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        let hex = "40000000000000000000000000000000224698fc094cf91b992d30ed00000001";
+        let expected = big_uint::from_str_radix(hex, 16).unwrap();
+        assert_eq!(rns.new_from_str(hex).value(), expected);
+        assert_eq!(rns.new_from_str(&format!("0x{}", hex)).value(), expected);
+        assert_eq!(rns.new_from_str(hex).value(), rns.wrong_modulus);
+
+        let max = "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+        assert_eq!(rns.new_from_str(max).value(), (big_uint::one() << 256usize) - 1usize);
+    }
+
+    #[test]
+    #[should_panic(expected = "value does not fit into 256 bits")]
+    fn test_new_from_str_overflow() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+        rns.new_from_str("0x10000000000000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test]
+    fn test_integer_formatting() {
+        use super::Limb;
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // 2^64 + 255 has two non zero limbs
+        let integer = rns.new_from_big((big_uint::one() << 64usize) + 255usize);
+        assert_eq!(format!("{}", integer), "18446744073709551871");
+        assert_eq!(format!("{:x}", integer), "100000000000000ff");
+        assert_eq!(format!("{:#x}", integer), "0x100000000000000ff");
+
+        let limb = Limb::<Native>::from("ff");
+        assert_eq!(format!("{}", limb), "255");
+        assert_eq!(format!("{:x}", limb), "ff");
+        assert_eq!(format!("{}", integer.limb(1)), "1");
+    }
+
+    #[test]
+    fn test_integer_value_equality() {
+        use std::collections::HashSet;
+
+        let e = big_uint::from(0xdeadbeefu64) << 100usize;
+        let a = Integer::<Fq>::from_big(e.clone(), NUMBER_OF_LIMBS, 64);
+        let b = Integer::<Fq>::from_big(e.clone(), NUMBER_OF_LIMBS, 60);
+        assert_ne!(a.limbs(), b.limbs());
+        assert_eq!(a, b);
+
+        let c = Integer::<Fq>::from_big(e + 1usize, NUMBER_OF_LIMBS, 64);
+        assert_ne!(a, c);
+
+        let set: HashSet<Integer<Fq>> = vec![a, b, c].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_new_from_repr() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        for _ in 0..100 {
+            let fe = Wrong::rand();
+            let a = rns.new_from_repr(&fe.to_bytes());
+            let b = rns.new_in_crt(fe);
+            assert_eq!(a.limbs(), b.limbs());
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_new_from_repr() {
+        use halo2::pasta::Fp as Wrong;
+        use halo2::pasta::Fq as Native;
+        use std::time::Instant;
+        let bit_len_limb = 64;
+
+        let rns = Rns::<Wrong, Native>::construct(bit_len_limb);
+
+        // both paths start from the bytes a host side library holds,
+        // going through the field element costs an extra conversion
+        let reprs: Vec<[u8; 32]> = (0..100_000).map(|_| Wrong::rand().to_bytes()).collect();
+
+        let start = Instant::now();
+        for repr in reprs.iter() {
+            rns.new_from_repr(repr);
+        }
+        let from_repr = start.elapsed();
+
+        let start = Instant::now();
+        for repr in reprs.iter() {
+            rns.new_in_crt(Wrong::from_bytes(repr).unwrap());
+        }
+        let from_field_element = start.elapsed();
+
+        println!("new_from_repr: {:?}, from_bytes and new_in_crt: {:?}", from_repr, from_field_element);
+        assert!(from_repr < from_field_element);
+    }
+
+    #[test]
+    #[cfg(feature = "arkworks")]
+    fn test_arkworks_field() {
+        use super::{compose, compose_fe, decompose_fe, Ark, RnsError, RnsParams};
+        use ark_bn254::{Fq as Wrong, Fr as Native};
+        use num_traits::Num;
+
+        let wrong_modulus = field_modulus::<Ark<Wrong>>();
+        let native_modulus = field_modulus::<Ark<Native>>();
+        let bn254_scalar = big_uint::from_str_radix("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001", 16).unwrap();
+        assert_eq!(native_modulus, bn254_scalar);
+
+        let params = RnsParams::construct::<Ark<Wrong>, Ark<Native>>(68).unwrap();
+        assert_eq!(params.wrong_modulus, wrong_modulus);
+        assert_eq!(compose(params.wrong_modulus_decomposed.clone(), 68), wrong_modulus);
+        let binary_modulus = big_uint::one() << (68 * NUMBER_OF_LIMBS);
+        assert_eq!(compose(params.negative_wrong_modulus.clone(), 68), binary_modulus - wrong_modulus.clone());
+        assert_eq!(params.most_significant_limb_max_val, (big_uint::one() << (254 - 68 * 3)) - 1usize);
+        assert_eq!(RnsParams::construct::<Ark<Wrong>, Ark<Native>>(66), Err(RnsError::LimbNotLookupAligned));
+
+        let e = native_modulus.clone() - 1usize;
+        let fe: Ark<Native> = big_to_fe(e.clone());
+        assert_eq!(fe_to_big(fe), e);
+        assert_eq!(big_to_fe::<Ark<Native>>(native_modulus), big_to_fe(big_uint::zero()));
+
+        let limbs = decompose_fe(fe, NUMBER_OF_LIMBS, 68);
+        assert!(limbs.iter().all(|limb| fe_to_big(*limb).bits() <= 68));
+        assert_eq!(compose_fe(limbs, 68), e);
+    }
 }